@@ -3,7 +3,7 @@ use std::fs::File;
 use std::io::Write;
 
 #[derive(Serialize)]
-struct Room { id: usize, name: String, capacity: u32, is_laboratory: bool, building_id: u32, free: Vec<String> }
+struct Room { id: usize, name: String, capacity: u32, is_laboratory: bool, building_id: u32, unavailable: Vec<(u32, u32)> }
 #[derive(Serialize)]
 struct Teacher { id: usize, name: String, course_id: Vec<usize> }
 #[derive(Serialize)]
@@ -15,9 +15,9 @@ struct TimetableInput { rooms: Vec<Room>, teachers: Vec<Teacher>, groups: Vec<Gr
 
 fn main() {
     let mut rooms = Vec::new();
-    for i in 1..=2 { rooms.push(Room { id: 100+i, name: format!("Amphitheater {}", i), capacity: 300, is_laboratory: false, building_id: 1, free: vec![] }); }
-    for i in 1..=8 { rooms.push(Room { id: 200+i, name: format!("Seminar Room {}", i), capacity: 40, is_laboratory: false, building_id: 1, free: vec![] }); }
-    for i in 1..=8 { rooms.push(Room { id: 300+i, name: format!("Laboratory {}", i), capacity: 40, is_laboratory: true, building_id: 2, free: vec![] }); }
+    for i in 1..=2 { rooms.push(Room { id: 100+i, name: format!("Amphitheater {}", i), capacity: 300, is_laboratory: false, building_id: 1, unavailable: vec![] }); }
+    for i in 1..=8 { rooms.push(Room { id: 200+i, name: format!("Seminar Room {}", i), capacity: 40, is_laboratory: false, building_id: 1, unavailable: vec![] }); }
+    for i in 1..=8 { rooms.push(Room { id: 300+i, name: format!("Laboratory {}", i), capacity: 40, is_laboratory: true, building_id: 2, unavailable: vec![] }); }
 
     let mut groups = Vec::new();
     for y in 1..=3 {
@@ -27,6 +27,7 @@ fn main() {
     }
 
     let mut courses = Vec::new();
+    let mut teachers = Vec::new();
     let mut course_id_counter = 1;
 
     for y in 1..=3 {
@@ -34,10 +35,20 @@ fn main() {
 
         for s in 1..=6 {
             let subject_name = format!("Y{} Subj {}", y, s);
+
+            // One lecturer, one seminar teacher and one lab teacher per subject
+            let lecturer_id = y * 100 + s * 10 + 1;
+            let seminar_prof_id = lecturer_id + 1;
+            let lab_prof_id = lecturer_id + 2;
+            let lecturer = teachers.len();
+            teachers.push(Teacher { id: lecturer_id, name: format!("{} Lecturer", subject_name), course_id: vec![] });
+            teachers.push(Teacher { id: seminar_prof_id, name: format!("{} Seminar Prof", subject_name), course_id: vec![] });
+            teachers.push(Teacher { id: lab_prof_id, name: format!("{} Lab Prof", subject_name), course_id: vec![] });
             
             let lecture_id = course_id_counter;
             course_id_counter += 1;
-            courses.push(Course { id: lecture_id, subject_name: format!("{} (Lecture)", subject_name), professor_id: 1, group_ids: year_groups.clone(), required_hours: 2, required_lab: false });
+            courses.push(Course { id: lecture_id, subject_name: format!("{} (Lecture)", subject_name), professor_id: lecturer_id, group_ids: year_groups.clone(), required_hours: 2, required_lab: false });
+            teachers[lecturer].course_id.push(lecture_id);
 
             for g in &mut groups {
                 if g.id / 10 == y { g.courses.push(lecture_id); }
@@ -48,11 +59,13 @@ fn main() {
 
                 let sem_id = course_id_counter;
                 course_id_counter += 1;
-                courses.push(Course { id: sem_id, subject_name: format!("{} (Sem G{})", subject_name, g_idx), professor_id: 2, group_ids: vec![g_id], required_hours: 2, required_lab: false });
+                courses.push(Course { id: sem_id, subject_name: format!("{} (Sem G{})", subject_name, g_idx), professor_id: seminar_prof_id, group_ids: vec![g_id], required_hours: 2, required_lab: false });
+                teachers[lecturer + 1].course_id.push(sem_id);
 
                 let lab_id = course_id_counter;
                 course_id_counter += 1;
-                courses.push(Course { id: lab_id, subject_name: format!("{} (Lab G{})", subject_name, g_idx), professor_id: 3, group_ids: vec![g_id], required_hours: 2, required_lab: true });
+                courses.push(Course { id: lab_id, subject_name: format!("{} (Lab G{})", subject_name, g_idx), professor_id: lab_prof_id, group_ids: vec![g_id], required_hours: 2, required_lab: true });
+                teachers[lecturer + 2].course_id.push(lab_id);
 
                 for g in &mut groups {
                     if g.id == g_id {
@@ -64,7 +77,7 @@ fn main() {
         }
     }
 
-    let input = TimetableInput { rooms, teachers: vec![], groups, courses };
+    let input = TimetableInput { rooms, teachers, groups, courses };
     
     // Save to JSON
     let json = serde_json::to_string_pretty(&input).unwrap();
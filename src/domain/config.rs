@@ -1,3 +1,8 @@
+use crate::domain::input_wrapper::TimetableInput;
+use crate::domain::teacher::TeacherLimits;
+use crate::domain::weights::Weights;
+use crate::solver::construct::InitialSolution;
+use crate::solver::moves::MoveMix;
 use serde::Deserialize;
 use std::fs;
 
@@ -5,14 +10,178 @@ use std::fs;
 pub struct Config{
     pub start_temp: f64,
     pub cooling_rate: f64,
+    /// Annealing: estimate the start temperature so that this share of worsening moves is accepted at first
+    /// (e.g. 0.8). Overrides `start_temp`.
+    #[serde(default)]
+    pub target_acceptance: Option<f64>,
+    /// Annealing: derive the cooling rate from the iteration or time budget so the temperature ends here.
+    /// Overrides `cooling_rate`.
+    #[serde(default)]
+    pub final_temp: Option<f64>,
+    /// Annealing: reheat after this many iterations without a new best schedule.
+    #[serde(default)]
+    pub reheat_after: Option<u32>,
+    /// Annealing: share of the start temperature a reheat goes back to (0.5 when not set).
+    #[serde(default)]
+    pub reheat_ratio: Option<f64>,
     pub max_iterations: u32,
     pub file_name: String,
+    /// Where to write the solved schedule as JSON (original IDs). Skipped when not set.
+    #[serde(default)]
+    pub output_file: Option<String>,
+    /// Where to write the penalty breakdown as JSON. Skipped when not set.
+    #[serde(default)]
+    pub report_file: Option<String>,
+    /// Seed of the solver's RNG. A random one is picked (and printed) when not set.
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Wall-clock budget of the solver in seconds.
+    #[serde(default)]
+    pub time_limit_secs: Option<f64>,
+    /// Stop after this many iterations without a new best schedule.
+    #[serde(default)]
+    pub max_no_improvement: Option<u32>,
+    /// Stop once there is no hard (or medium) violation left and the soft
+    /// penalty is at or below this value (0 when not set).
+    #[serde(default)]
+    pub target_penalty: Option<u32>,
+    /// Number of annealing chains run in parallel, each with its own seed (1 when not set).
+    #[serde(default)]
+    pub chains: Option<usize>,
+    /// Every how many iterations parallel chains share their best schedule. Not shared when not set.
+    #[serde(default)]
+    pub share_interval: Option<u64>,
+    /// Name of the solver to run (see `solver::registry`). Simulated annealing when not set.
+    #[serde(default)]
+    pub algorithm: Option<String>,
+    /// A schedule written by an earlier run (`output_file`) to start from and stay close to.
+    #[serde(default)]
+    pub previous_schedule: Option<String>,
+    /// Penalty per session moved away from its previous time, and again from its previous room (10 when not set).
+    #[serde(default)]
+    pub perturbation_weight: Option<u32>,
+    /// How solvers build their starting schedule: "random" (default) or "greedy".
+    #[serde(default)]
+    pub initial_solution: InitialSolution,
+    /// Relative weights of the move kinds used by annealing and tabu search (`[move_mix]` table).
+    #[serde(default)]
+    pub move_mix: MoveMix,
+    /// Points per broken constraint (`[weights]` table). Each key set here overrides that key of the
+    /// input file's `weights`; the others keep the input's value.
+    #[serde(default)]
+    pub weights: Option<toml::Table>,
+    /// Default load limits of every teacher (`[teacher_limits]` table). Each limit set here overrides
+    /// that limit of the input file's `teacher_limits`; limits on a teacher itself still win.
+    #[serde(default)]
+    pub teacher_limits: Option<TeacherLimits>,
+    /// Tabu search: iterations a moved (course, slot) stays tabu.
+    #[serde(default)]
+    pub tabu_tenure: Option<u64>,
+    /// Tabu search: random moves evaluated per iteration.
+    #[serde(default)]
+    pub tabu_sample_size: Option<usize>,
+    /// Genetic: schedules per generation (30 when not set).
+    #[serde(default)]
+    pub population_size: Option<usize>,
+    /// Genetic: contestants per tournament selection (3 when not set).
+    #[serde(default)]
+    pub tournament_size: Option<usize>,
+    /// Genetic: probability that a session of a child is moved at random (0.02 when not set).
+    #[serde(default)]
+    pub mutation_rate: Option<f64>,
+    /// Genetic: hill-climbing moves applied to every child; above 0 makes it memetic.
+    #[serde(default)]
+    pub local_search_steps: Option<u32>,
 }
 
 impl Config{
     pub fn load() -> Result<Self, Box<dyn std::error::Error>>{
         let content = fs::read_to_string("config.toml")?;
         let config: Config = toml::from_str(&content)?;
+        config.validate()?;
         Ok(config)
     }
+
+    /// Checks the values that toml cannot, e.g. a `time_limit_secs` that is negative, NaN or infinite,
+    /// a `reheat_ratio` outside [0, 1), a `target_acceptance` outside (0, 1) or a `[weights]` key
+    /// of the wrong type.
+    pub fn validate(&self) -> Result<(), String>{
+        if let Some(seconds) = self.time_limit_secs {
+            check_time_limit(seconds, &seconds.to_string())?;
+        }
+        if let Some(ratio) = self.reheat_ratio
+            && !(0.0..1.0).contains(&ratio)
+        {
+            return Err(format!("invalid reheat ratio '{}', expected at least 0 and below 1", ratio));
+        }
+        if let Some(acceptance) = self.target_acceptance
+            && !(acceptance > 0.0 && acceptance < 1.0)
+        {
+            return Err(format!("invalid target acceptance '{}', expected above 0 and below 1", acceptance));
+        }
+        if let Some(table) = &self.weights {
+            merge_weights(&Weights::default(), table)?;
+        }
+        Ok(())
+    }
+
+    /// Puts the keys of the `[weights]` and `[teacher_limits]` tables over the input's own values.
+    pub fn apply_to(&self, input: &mut TimetableInput) -> Result<(), String>{
+        if let Some(table) = &self.weights {
+            input.weights = merge_weights(&input.weights, table)?;
+        }
+        if let Some(limits) = self.teacher_limits {
+            input.teacher_limits = limits.or(input.teacher_limits);
+        }
+        Ok(())
+    }
+
+    /// Overrides config values with command line flags.
+    ///
+    /// Supported flags: `--algorithm <name>`, `--initial <random|greedy>`, `--warm-start <file>`, `--seed <u64>`, `--time-limit <seconds>`, `--chains <n>`.
+    pub fn apply_args(&mut self, args: impl IntoIterator<Item = String>) -> Result<(), String>{
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or_else(|| format!("missing value for {}", flag));
+            match flag.as_str() {
+                "--algorithm" => self.algorithm = Some(value()?),
+                "--initial" => self.initial_solution = value()?.parse()?,
+                "--warm-start" => self.previous_schedule = Some(value()?),
+                "--seed" => {
+                    let value = value()?;
+                    self.seed = Some(value.parse().map_err(|_| format!("invalid seed '{}'", value))?);
+                }
+                "--time-limit" => {
+                    let value = value()?;
+                    let seconds: f64 = value.parse().map_err(|_| format!("invalid time limit '{}'", value))?;
+                    self.time_limit_secs = Some(check_time_limit(seconds, &value)?);
+                }
+                "--chains" => {
+                    let value = value()?;
+                    match value.parse() {
+                        Ok(chains) if chains > 0 => self.chains = Some(chains),
+                        _ => return Err(format!("invalid chain count '{}'", value)),
+                    }
+                }
+                _ => return Err(format!("unknown argument '{}'", flag)),
+            }
+        }
+        Ok(())
+    }
+}
+
+/// `weights` with the keys of `table` put over them, one key at a time.
+fn merge_weights(weights: &Weights, table: &toml::Table) -> Result<Weights, String> {
+    let mut merged = toml::Table::try_from(weights).map_err(|e| format!("invalid [weights] table: {}", e))?;
+    merged.extend(table.clone());
+    merged.try_into().map_err(|e| format!("invalid [weights] table: {}", e))
+}
+
+/// A time limit must be a finite number of seconds, at least 0.
+fn check_time_limit(seconds: f64, value: &str) -> Result<f64, String> {
+    if seconds.is_finite() && seconds >= 0.0 {
+        Ok(seconds)
+    } else {
+        Err(format!("invalid time limit '{}'", value))
+    }
 }
@@ -1,5 +1,5 @@
 use serde::{Deserialize, Serialize};
-use crate::domain::group::Group;
+use crate::domain::{group::Group, week_grid::WeekGrid};
 
 /// Represents a University Course (Subject) that needs to be scheduled.
 /// 
@@ -20,7 +20,8 @@ pub struct Course {
     /// If multiple groups are listed, they are merged into a single large class.
     pub group_ids: Vec<usize>,
     
-    /// Number of 2-hour slots required per week (usually 1).
+    /// Number of teaching hours required per week (usually 2, i.e. one slot).
+    /// See [`Course::sessions_needed`] for how many slots this takes.
     pub required_hours: u32,
     
     /// If true, this course requires a room with `is_laboratory = true`.
@@ -34,11 +35,21 @@ impl Course {
     /// 
     /// # Arguments
     /// * `vec_of_groups` - The full list of groups to look up student counts.
-    pub fn capacity_needed(&self, vec_of_groups: &Vec<Group>) -> u32 {
+    pub fn capacity_needed(&self, vec_of_groups: &[Group]) -> u32 {
         let mut capacity_needed: u32 = 0;
         for &group_id in &self.group_ids {
             capacity_needed += vec_of_groups[group_id].numbers_of_students;
         }
         capacity_needed
     }
+
+    /// Number of slots (sessions) this course needs every week.
+    ///
+    /// `required_hours` is rounded up to whole slots of `week.slot_minutes`,
+    /// and every course gets at least one. Saturates at `u32::MAX` for absurd hours,
+    /// which `validate_input` reports.
+    pub fn sessions_needed(&self, week: &WeekGrid) -> u32 {
+        let sessions = (u64::from(self.required_hours) * 60).div_ceil(u64::from(week.slot_minutes)).max(1);
+        u32::try_from(sessions).unwrap_or(u32::MAX)
+    }
 }
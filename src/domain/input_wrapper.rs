@@ -1,15 +1,40 @@
-use crate::domain::{course::Course, teacher::Teacher, room::Room, group::Group};
+use crate::domain::{course::Course, teacher::{Teacher, TeacherLimits}, room::Room, group::Group, week_grid::WeekGrid};
+use crate::domain::{pin::Pin, previous_schedule::PreviousSchedule, schedule::Session, travel::TravelTime, weights::Weights};
 use serde::{Deserialize, Serialize};
 /// The Read-Only "World" data.
 /// 
 /// This struct holds all the static information loaded from the JSON file.
 /// It is passed to the cost function to provide context (Room capacities, Group sizes).
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct TimetableInput {
     pub rooms: Vec<Room>,
     pub teachers: Vec<Teacher>,
     pub courses: Vec<Course>,
     pub groups: Vec<Group>,
+
+    /// Days and slots of the teaching week (defaults to Mon-Fri, 6 x 2h slots).
+    #[serde(default)]
+    pub week: WeekGrid,
+
+    /// Sessions with a fixed day, slot and/or room that the solver must not move.
+    #[serde(default)]
+    pub pinned: Vec<Pin>,
+
+    /// Minutes between buildings. Without it, back-to-back classes in different buildings are always a Teleportation.
+    #[serde(default)]
+    pub travel_times: Vec<TravelTime>,
+
+    /// Points per broken constraint (defaults to the built-in weights).
+    #[serde(default)]
+    pub weights: Weights,
+
+    /// Load limits of every teacher that does not set its own (no limits by default).
+    #[serde(default)]
+    pub teacher_limits: TeacherLimits,
+
+    /// Last run's schedule, when re-solving with a warm start (not part of the input file).
+    #[serde(skip)]
+    pub previous: Option<PreviousSchedule>,
 }
 
 impl TimetableInput {
@@ -25,4 +50,87 @@ impl TimetableInput {
     pub fn get_room(&self, room_id: usize) -> &Room {
         &self.rooms[room_id]
     }
+
+    /// The load limits of teacher `teacher_id` (by index): its own, or else `teacher_limits`.
+    pub fn limits_of(&self, teacher_id: usize) -> TeacherLimits {
+        self.teachers[teacher_id].limits.or(self.teacher_limits)
+    }
+
+    /// Minutes needed to get from `building` to `other_building` (0 within a building).
+    ///
+    /// `None` if `travel_times` has no entry for the two buildings.
+    pub fn travel_minutes(&self, building: usize, other_building: usize) -> Option<u32> {
+        if building == other_building {
+            return Some(0);
+        }
+        self.travel_times.iter()
+            .find(|travel| travel.connects(building, other_building))
+            .map(|travel| travel.minutes)
+    }
+
+    /// Lists the (Day, Slot) cells of the week in which `room_id` is not blocked.
+    pub fn open_cells(&self, room_id: usize) -> Vec<(u32, u32)> {
+        let room = self.get_room(room_id);
+        let mut cells = Vec::new();
+        for day in 0..self.week.day_count() as u32 {
+            for slot in 0..self.week.slot_count() as u32 {
+                if !room.is_unavailable(day, slot) {
+                    cells.push((day, slot));
+                }
+            }
+        }
+        cells
+    }
+
+    /// Rooms (by index) that `course_id` can use: big enough for all its groups,
+    /// a laboratory if the course needs one, and open in at least one cell of the week.
+    pub fn feasible_rooms(&self, course_id: usize) -> Vec<usize> {
+        let course = self.get_course(course_id);
+        let needed = course.capacity_needed(&self.groups);
+        (0..self.rooms.len())
+            .filter(|&room_id| {
+                let room = self.get_room(room_id);
+                room.capacity >= needed
+                    && (room.is_laboratory || !course.required_lab)
+                    && !self.open_cells(room_id).is_empty()
+            })
+            .collect()
+    }
+
+    /// The room domain of every course: CourseID -> [`Self::feasible_rooms`].
+    ///
+    /// Solvers only sample rooms from these sets.
+    pub fn room_domains(&self) -> Vec<Vec<usize>> {
+        (0..self.courses.len()).map(|course_id| self.feasible_rooms(course_id)).collect()
+    }
+
+    /// The pin that applies to `session`, if any (the first one listed wins).
+    pub fn pin(&self, session: Session) -> Option<&Pin> {
+        self.pinned.iter().find(|pin| pin.course_id == session.course_id && pin.covers(session.index))
+    }
+
+    /// Every (Day, Slot, RoomID) a session pinned by `pin` may take.
+    ///
+    /// Rooms come from the pin, or from `rooms` (the course's domain) when it
+    /// does not fix one; cells are the open cells of each room that match the pin.
+    /// If the pin leaves no open cell, the blocked ones are used rather than none.
+    pub fn pinned_options(&self, pin: &Pin, rooms: &[usize]) -> Vec<(u32, u32, usize)> {
+        let rooms = match pin.room_id {
+            Some(room_id) => vec![room_id],
+            None if rooms.is_empty() => (0..self.rooms.len()).collect(),
+            None => rooms.to_vec(),
+        };
+        let open: Vec<_> = rooms.iter()
+            .flat_map(|&room_id| self.open_cells(room_id).into_iter().map(move |(day, slot)| (day, slot, room_id)))
+            .filter(|&option| pin.allows(option))
+            .collect();
+        if !open.is_empty() {
+            return open;
+        }
+        rooms.iter()
+            .flat_map(|&room_id| (0..self.week.cell_count()).map(move |cell| (cell, room_id)))
+            .map(|(cell, room_id)| ((cell / self.week.slot_count()) as u32, (cell % self.week.slot_count()) as u32, room_id))
+            .filter(|&option| pin.allows(option))
+            .collect()
+    }
 }
@@ -5,3 +5,10 @@ pub mod course;
 pub mod input_wrapper;
 pub mod schedule;
 pub mod config;
+pub mod week_grid;
+pub mod report;
+pub mod previous_schedule;
+pub mod pin;
+pub mod score;
+pub mod weights;
+pub mod travel;
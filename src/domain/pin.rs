@@ -0,0 +1,47 @@
+use serde::{Deserialize, Serialize};
+
+/// A course (or one of its sessions) whose time and/or room was decided up front,
+/// e.g. a lecture in an amphitheater shared with another faculty.
+///
+/// The solver never moves a pinned session away from what is given here: fields
+/// that are set are fixed, the others are still chosen by the solver.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Pin {
+    /// The pinned course (Database ID in the file, index after normalization).
+    pub course_id: usize,
+
+    /// Which session of the course is pinned; every session when not set.
+    #[serde(default)]
+    pub session_index: Option<u32>,
+
+    /// Index of the fixed day in the week grid.
+    #[serde(default)]
+    pub day: Option<u32>,
+
+    /// Index of the fixed slot in the day.
+    #[serde(default)]
+    pub slot: Option<u32>,
+
+    /// The fixed room (Database ID in the file, index after normalization).
+    #[serde(default)]
+    pub room_id: Option<usize>,
+}
+
+impl Pin {
+    /// Returns true if this pin covers session `index` of its course.
+    pub fn covers(&self, index: u32) -> bool {
+        self.session_index.is_none_or(|pinned| pinned == index)
+    }
+
+    /// Returns true if (Day, Slot, RoomID) keeps every fixed field.
+    pub fn allows(&self, (day, slot, room_id): (u32, u32, usize)) -> bool {
+        self.day.is_none_or(|d| d == day)
+            && self.slot.is_none_or(|s| s == slot)
+            && self.room_id.is_none_or(|r| r == room_id)
+    }
+
+    /// The only assignment left when day, slot and room are all fixed.
+    pub fn fixed(&self) -> Option<(u32, u32, usize)> {
+        Some((self.day?, self.slot?, self.room_id?))
+    }
+}
@@ -0,0 +1,26 @@
+use crate::domain::schedule::Session;
+
+/// The schedule of a previous run, used as a warm start and for the
+/// minimal-perturbation objective.
+///
+/// Sessions are looked up by course and session index, so schedules that are
+/// only partially built (or list their sessions in another order) score the same.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PreviousSchedule {
+    /// CourseID -> session index -> previous (Day, Slot, RoomID); `None` for new sessions.
+    pub assignments: Vec<Vec<Option<(u32, u32, usize)>>>,
+    /// Penalty for a session whose Day/Slot changed, and again if its room changed.
+    pub weight: u32,
+}
+
+impl PreviousSchedule {
+    /// Where `session` was placed in the previous schedule, if it existed there.
+    pub fn get(&self, session: Session) -> Option<(u32, u32, usize)> {
+        self.assignments.get(session.course_id)?.get(session.index as usize).copied().flatten()
+    }
+
+    /// Number of sessions that have a previous assignment.
+    pub fn known_sessions(&self) -> usize {
+        self.assignments.iter().flatten().filter(|assignment| assignment.is_some()).count()
+    }
+}
@@ -0,0 +1,236 @@
+use crate::domain::{group::Group, input_wrapper::TimetableInput, score::{Level, Score}, teacher::Teacher};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+
+/// Every constraint the cost function knows about.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub enum ConstraintKind {
+    RoomCapacity,
+    LabMismatch,
+    RoomUnavailable,
+    RoomDoubleBooking,
+    GroupCollision,
+    TeacherCollision,
+    Teleportation,
+    TeacherUnavailable,
+    /// More teaching hours in a day than the teacher's `max_hours_per_day`.
+    TeacherDailyHours,
+    /// A longer run of back-to-back classes than the teacher's `max_consecutive_slots`.
+    TeacherConsecutiveSlots,
+    /// Fewer days without classes than the teacher's `min_free_days`.
+    TeacherFreeDays,
+    /// Back-to-back classes in buildings that can be reached during the break.
+    TravelTime,
+    Gap,
+    /// No free slot in the lunch window of a day (soft unless `weights.lunch_break_level` says otherwise).
+    LunchBreak,
+    LongDay,
+    TeacherUndesiredSlot,
+    TeacherOutsidePreferred,
+    /// A session moved away from its place in the previous schedule.
+    Perturbation,
+}
+
+impl ConstraintKind {
+    /// The level of the [`Score`] this constraint counts towards.
+    pub fn level(self) -> Level {
+        match self {
+            ConstraintKind::RoomCapacity
+            | ConstraintKind::LabMismatch
+            | ConstraintKind::RoomUnavailable
+            | ConstraintKind::RoomDoubleBooking
+            | ConstraintKind::GroupCollision
+            | ConstraintKind::TeacherCollision
+            | ConstraintKind::Teleportation
+            | ConstraintKind::TeacherUnavailable
+            | ConstraintKind::TeacherDailyHours
+            | ConstraintKind::TeacherConsecutiveSlots
+            | ConstraintKind::TeacherFreeDays => Level::Hard,
+            ConstraintKind::TravelTime
+            | ConstraintKind::Gap
+            | ConstraintKind::LunchBreak
+            | ConstraintKind::LongDay
+            | ConstraintKind::TeacherUndesiredSlot
+            | ConstraintKind::TeacherOutsidePreferred
+            | ConstraintKind::Perturbation => Level::Soft,
+        }
+    }
+}
+
+/// Who a per-entity check is running for: a Group or a Teacher (index in the normalized input).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttendeeId {
+    Group(usize),
+    Teacher(usize),
+}
+
+/// Implemented by the entities that attend sessions, so the generic checks
+/// in `Schedule` know whose week they are scoring.
+pub trait Attendee {
+    fn attendee_id(index: usize) -> AttendeeId;
+}
+
+impl Attendee for Group {
+    fn attendee_id(index: usize) -> AttendeeId {
+        AttendeeId::Group(index)
+    }
+}
+
+impl Attendee for Teacher {
+    fn attendee_id(index: usize) -> AttendeeId {
+        AttendeeId::Teacher(index)
+    }
+}
+
+/// One broken constraint and everything involved in it.
+///
+/// All IDs are the original "Database IDs" from the input file.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Violation {
+    pub kind: ConstraintKind,
+    /// The level of the score `weight` counts towards (that of `kind`, unless configured otherwise).
+    pub level: Level,
+    /// Penalty points this violation adds to its level of the total.
+    pub weight: u64,
+    pub courses: Vec<usize>,
+    pub rooms: Vec<usize>,
+    pub groups: Vec<usize>,
+    pub teachers: Vec<usize>,
+    pub day: Option<u32>,
+    pub slot: Option<u32>,
+}
+
+impl Violation {
+    /// An empty violation of `kind`; the builder methods below fill in who is involved.
+    pub fn new(kind: ConstraintKind) -> Self {
+        Self {
+            kind,
+            level: kind.level(),
+            weight: 0,
+            courses: vec![],
+            rooms: vec![],
+            groups: vec![],
+            teachers: vec![],
+            day: None,
+            slot: None,
+        }
+    }
+
+    /// Adds the courses (by index) to the violation.
+    pub fn with_courses(mut self, input: &TimetableInput, courses: impl IntoIterator<Item = usize>) -> Self {
+        self.courses.extend(courses.into_iter().map(|c| input.courses[c].id));
+        self
+    }
+
+    /// Adds the rooms (by index) to the violation.
+    pub fn with_rooms(mut self, input: &TimetableInput, rooms: impl IntoIterator<Item = usize>) -> Self {
+        self.rooms.extend(rooms.into_iter().map(|r| input.rooms[r].id));
+        self
+    }
+
+    /// Adds a Group or Teacher to the violation.
+    pub fn with_attendee(mut self, input: &TimetableInput, attendee: AttendeeId) -> Self {
+        match attendee {
+            AttendeeId::Group(g) => self.groups.push(input.groups[g].id),
+            AttendeeId::Teacher(t) => self.teachers.push(input.teachers[t].id),
+        }
+        self
+    }
+
+    /// Sets the time of the violation.
+    pub fn at(mut self, day: u32, slot: Option<u32>) -> Self {
+        self.day = Some(day);
+        self.slot = slot;
+        self
+    }
+}
+
+/// Where the cost function sends its penalties.
+///
+/// Summing into a [`Score`] keeps the solver fast; a [`PenaltyReport`] also keeps
+/// the details. The violation is only built (from `kind`) when the sink asks for it.
+pub trait PenaltySink {
+    /// Adds `weight` points on `level`, which may differ from `kind.level()` when the level is configurable.
+    fn add_at(&mut self, kind: ConstraintKind, level: Level, weight: u64, violation: impl FnOnce(ConstraintKind) -> Violation);
+
+    fn add(&mut self, kind: ConstraintKind, weight: u64, violation: impl FnOnce(ConstraintKind) -> Violation) {
+        self.add_at(kind, kind.level(), weight, violation);
+    }
+}
+
+impl PenaltySink for Score {
+    fn add_at(&mut self, _: ConstraintKind, level: Level, weight: u64, _: impl FnOnce(ConstraintKind) -> Violation) {
+        *self += Score::of(level, weight);
+    }
+}
+
+/// The full explanation of a schedule's penalty.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PenaltyReport {
+    /// Same value as `Schedule::calculate_penalty`.
+    pub total: Score,
+    pub violations: Vec<Violation>,
+}
+
+impl PenaltySink for PenaltyReport {
+    fn add_at(&mut self, kind: ConstraintKind, level: Level, weight: u64, violation: impl FnOnce(ConstraintKind) -> Violation) {
+        if weight == 0 {
+            return;
+        }
+        let mut violation = violation(kind);
+        violation.level = level;
+        violation.weight = weight;
+        self.total += Score::of(violation.level, weight);
+        self.violations.push(violation);
+    }
+}
+
+impl PenaltyReport {
+    /// Number of violations and total weight per constraint kind.
+    pub fn by_kind(&self) -> BTreeMap<ConstraintKind, (usize, u64)> {
+        let mut summary = BTreeMap::new();
+        for violation in &self.violations {
+            let entry = summary.entry(violation.kind).or_insert((0, 0));
+            entry.0 += 1;
+            entry.1 += violation.weight;
+        }
+        summary
+    }
+
+    /// Serializes the report as pretty-printed JSON.
+    pub fn to_json(&self) -> serde_json::Result<String> {
+        serde_json::to_string_pretty(self)
+    }
+}
+
+impl fmt::Display for PenaltyReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Total penalty: {}", self.total)?;
+        for (kind, (count, weight)) in self.by_kind() {
+            let level = self.violations.iter().find(|v| v.kind == kind).map_or(kind.level(), |v| v.level);
+            writeln!(f, "  {:<24} {:>5} x  {:>9} {} points", format!("{:?}", kind), count, weight, level)?;
+        }
+        for violation in &self.violations {
+            write!(f, "  - {:?} (+{} {})", violation.kind, violation.weight, violation.level)?;
+            if let Some(day) = violation.day {
+                write!(f, " day {}", day)?;
+            }
+            if let Some(slot) = violation.slot {
+                write!(f, " slot {}", slot)?;
+            }
+            for (label, ids) in [
+                ("courses", &violation.courses),
+                ("rooms", &violation.rooms),
+                ("groups", &violation.groups),
+                ("teachers", &violation.teachers),
+            ] {
+                if !ids.is_empty() {
+                    write!(f, " {}: {:?}", label, ids)?;
+                }
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
@@ -17,10 +17,19 @@ pub struct Room {
     pub is_laboratory: bool,
     
     /// Tracks identifying ID of the building (e.g., 1 for Main, 2 for FSEGA).
-    /// Used to calculate travel penalties.
+    /// Used to calculate travel penalties (see `TimetableInput::travel_times`).
     pub building_id: usize, 
     
-    // (Optional: If you aren't using 'free' yet, you can mark it as such)
-    /// Reserved for future optimization (pre-blocked slots).
-    pub free: Vec<Vec<u32>>,
+    /// (Day, Slot) pairs in which the room cannot be used
+    /// (conferences, maintenance, bookings by other faculties).
+    /// The solver never places a session here, and using one is a Hard Constraint violation.
+    #[serde(default)]
+    pub unavailable: Vec<(u32, u32)>,
+}
+
+impl Room {
+    /// Returns true if the room is blocked at `day` / `slot`.
+    pub fn is_unavailable(&self, day: u32, slot: u32) -> bool {
+        self.unavailable.contains(&(day, slot))
+    }
 }
@@ -1,223 +1,536 @@
 use crate::domain::input_wrapper::TimetableInput;
-use crate::domain::group::Group;
-const HARD_CONSTRAINT: u32 = 100000;
+use crate::domain::report::{Attendee, AttendeeId, ConstraintKind, PenaltyReport, PenaltySink, Violation};
+use crate::domain::score::Score;
+use crate::domain::weights::Weights;
+use serde::{Deserialize, Serialize};
 
+/// One weekly meeting of a course.
+///
+/// With the default 2-hour slots, a course with `required_hours = 4` needs
+/// two slots, so it is split into the sessions `(course_id, 0)` and `(course_id, 1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Session {
+    /// Index of the course in the normalized input.
+    pub course_id: usize,
+    /// Which meeting of the course this is (0 for the first one).
+    pub index: u32,
+}
 
-#[derive(Debug, Clone)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 /// Represents a candidate solution for the Timetable Problem.
-/// It contains a list of assignments where the index corresponds to the Course ID.
+/// It contains a list of sessions and, at the same index, the assignment of each session.
 pub struct Schedule {
+    /// Every session that has to be scheduled.
+    /// - Index: Session ID
+    pub sessions: Vec<Session>,
+
     /// A flat vector representing the gene code.
-    /// - Index: Course ID (from normalized input)
+    /// - Index: Session ID (same index as in `sessions`)
     /// - Value: (Day, Slot, RoomID)
-    ///   - Day: 0..4 (Mon-Fri)
-    ///   - Slot: 0..5 (2-hour blocks)
+    ///   - Day: Index in `input.week.days`
+    ///   - Slot: Index in `input.week.slots`
     ///   - RoomID: Index in the input.rooms vector
     pub assignments: Vec<(u32, u32, usize)>,
 }
 
 impl Schedule {
+    /// Builds a schedule from one assignment per session.
+    ///
+    /// The sessions are taken from [`Schedule::sessions_for`], so `assignments`
+    /// must list the sessions of course 0 first, then those of course 1, and so on.
+    ///
+    /// # Panics
+    /// Panics if the number of assignments does not match the number of sessions.
+    pub fn new(input: &TimetableInput, assignments: Vec<(u32, u32, usize)>) -> Self {
+        let sessions = Self::sessions_for(input);
+        assert_eq!(sessions.len(), assignments.len(), "Expected one assignment per session");
+        Self { sessions, assignments }
+    }
+
+    /// Lists the sessions needed by every course, grouped by course.
+    pub fn sessions_for(input: &TimetableInput) -> Vec<Session> {
+        input.courses.iter().enumerate()
+            .flat_map(|(course_id, course)| {
+                (0..course.sessions_needed(&input.week)).map(move |index| Session { course_id, index })
+            })
+            .collect()
+    }
+
+    /// Builds the Course ID -> Session IDs lookup.
+    pub fn sessions_by_course(&self, course_count: usize) -> Vec<Vec<usize>> {
+        let mut by_course = vec![Vec::new(); course_count];
+        for (session_id, session) in self.sessions.iter().enumerate() {
+            by_course[session.course_id].push(session_id);
+        }
+        by_course
+    }
+
     /// Calculates the total "Energy" (Cost) of this schedule.
     /// Lower energy means a better schedule.
     ///
+    /// Every constraint is weighted by `input.weights`; the numbers given in the
+    /// checks below are the defaults.
+    ///
+    /// Hard and soft penalties are kept apart in a [`Score`] and compared
+    /// lexicographically, so no amount of soft penalty outweighs a hard violation.
+    ///
     /// Currently sums up penalties from:
-    /// 1. Room Collisions (Hard Constraint)
+    /// 1. Room Collisions and Room Unavailability (Hard Constraint)
     /// 2. Room Capacity Overflow (Hard Constraint)
     /// 3. Laboratory Mismatches (Hard Constraint)
     /// 4. Student Group Collisions (Hard Constraint)
     /// 5. Teleportation / Building Distance (Hard Constraint)
     /// 6. Time Gaps between classes (Soft Constraint)
-    pub fn calculate_penalty(&self, input: &TimetableInput) -> u32 {
+    /// 7. Teacher Unavailability (Hard Constraint) and Preferences (Soft Constraint)
+    /// 8. Teacher Load: hours per day, back-to-back slots and free days (Hard Constraint)
+    /// 9. Moves away from the previous schedule, when re-solving (Soft Constraint)
+    pub fn calculate_penalty(&self, input: &TimetableInput) -> Score {
+        let mut penalty = Score::ZERO;
+        self.score(input, &mut penalty);
+        penalty
+    }
+
+    /// Same scoring as [`Schedule::calculate_penalty`], but lists every violation
+    /// with its constraint kind, weight and the courses/rooms/groups/teachers involved.
+    pub fn penalty_report(&self, input: &TimetableInput) -> PenaltyReport {
+        let mut report = PenaltyReport::default();
+        self.score(input, &mut report);
+        report
+    }
+
+    /// Runs every checker and sends their penalties to `sink`.
+    fn score<S: PenaltySink>(&self, input: &TimetableInput, sink: &mut S) {
         // We accumulate penalties from different checkers here
-        self.collision_grid(input) +
-            self.gap_teleportation_check(input, &input.groups, |g| g.courses.iter().copied()) + 
-            self.gap_teleportation_check(input, &input.teachers, |g| g.course_id.iter().copied())
+        self.collision_grid_into(input, sink);
+        self.gap_teleportation_into(input, &input.groups, |g| g.courses.iter().copied(), sink);
+        self.gap_teleportation_into(input, &input.teachers, |g| g.course_id.iter().copied(), sink);
+        self.teacher_availability_into(input, sink);
+        self.teacher_load_into(input, sink);
+        for (&session, &assignment) in self.sessions.iter().zip(&self.assignments) {
+            perturbation_penalty(input, session, assignment, sink);
+        }
+    }
+
+    /// Checks every session against the availability and preferences of its teachers.
+    ///
+    /// # Constraints Checked:
+    /// * **Unavailable:** The teacher cannot teach at this time (+1 hard)
+    /// * **Undesired:** The teacher would rather not teach at this time (+30 soft)
+    /// * **Not Preferred:** The teacher listed preferred slots and this is not one of them (+10 soft)
+    ///
+    /// # Returns
+    /// The total penalty score for these constraints.
+    pub fn teacher_availability_check(&self, input: &TimetableInput) -> Score {
+        let mut penalty = Score::ZERO;
+        self.teacher_availability_into(input, &mut penalty);
+        penalty
+    }
+
+    fn teacher_availability_into<S: PenaltySink>(&self, input: &TimetableInput, sink: &mut S) {
+        let sessions_by_course = self.sessions_by_course(input.courses.len());
+
+        for (teacher_id, teacher) in input.teachers.iter().enumerate() {
+            for &course_id in &teacher.course_id {
+                for &session_id in &sessions_by_course[course_id] {
+                    let (day, slot, _) = self.assignments[session_id];
+                    teacher_slot_penalty(input, teacher_id, course_id, day, slot, sink);
+                }
+            }
+        }
+    }
+
+    /// Checks the teaching load of every teacher against its limits ([`TimetableInput::limits_of`]).
+    ///
+    /// # Constraints Checked:
+    /// * **Daily Hours:** More slots in a day than fit in `max_hours_per_day` (+1 hard per extra slot)
+    /// * **Consecutive Slots:** A run of back-to-back classes longer than `max_consecutive_slots` (+1 hard per extra slot)
+    /// * **Free Days:** Fewer days without classes than `min_free_days` (+1 hard per missing day)
+    ///
+    /// # Returns
+    /// The total penalty score for these constraints.
+    pub fn teacher_load_check(&self, input: &TimetableInput) -> Score {
+        let mut penalty = Score::ZERO;
+        self.teacher_load_into(input, &mut penalty);
+        penalty
+    }
+
+    fn teacher_load_into<S: PenaltySink>(&self, input: &TimetableInput, sink: &mut S) {
+        let sessions_by_course = self.sessions_by_course(input.courses.len());
+
+        for (teacher_id, teacher) in input.teachers.iter().enumerate() {
+            // [Day][Slot] -> a session the teacher has there (collisions are scored elsewhere)
+            let mut week = vec![vec![None::<usize>; input.week.slot_count()]; input.week.day_count()];
+            for &course_id in &teacher.course_id {
+                for &session_id in &sessions_by_course[course_id] {
+                    let (day, slot, _) = self.assignments[session_id];
+                    week[day as usize][slot as usize].get_or_insert(session_id);
+                }
+            }
+
+            for (day, sessions) in week.iter().enumerate() {
+                teacher_day_load(input, teacher_id, day as u32, sessions, sink);
+            }
+            let busy_days = week.iter().filter(|sessions| sessions.iter().any(Option::is_some)).count();
+            teacher_week_load(input, teacher_id, busy_days, sink);
+        }
     }
 
     /// Checks for Hard Constraints related to Room Usage.
     ///
     /// # Constraints Checked:
-    /// * **Capacity:** Does the room fit all students? (+10,000 penalty)
-    /// * **Room Type:** If the course needs a Lab, is the room a Lab? (+10,000 penalty)
-    /// * **Double Booking:** Is the room already occupied at this time? (+10,000 penalty)
+    /// * **Capacity:** Does the room fit all students? (+1 hard)
+    /// * **Room Type:** If the course needs a Lab, is the room a Lab? (+1 hard)
+    /// * **Double Booking:** Is the room already occupied at this time? (+1 hard)
+    /// * **Unavailability:** Is the room blocked (`Room::unavailable`) at this time? (+1 hard)
     ///
     /// # Returns
     /// The total penalty score for these constraints.
-    pub fn collision_grid(&self, input: &TimetableInput) -> u32 {
-        let mut penalty: u32 = 0;
-        
+    pub fn collision_grid(&self, input: &TimetableInput) -> Score {
+        let mut penalty = Score::ZERO;
+        self.collision_grid_into(input, &mut penalty);
+        penalty
+    }
+
+    fn collision_grid_into<S: PenaltySink>(&self, input: &TimetableInput, sink: &mut S) {
         // A 3D Grid to track room usage: [Day][Slot][RoomID]
         // Used to detect double-booking in O(1) time.
-        let mut grid = vec![vec![vec![None::<usize>; input.rooms.len()]; 6]; 5];
+        let mut grid = vec![vec![vec![None::<usize>; input.rooms.len()]; input.week.slot_count()]; input.week.day_count()];
 
-        for (course_id, assignment) in self.assignments.iter().enumerate() {
+        for (session_id, (session, assignment)) in self.sessions.iter().zip(&self.assignments).enumerate() {
             let (day, slot, room_id) = *assignment;
             
-            // 1. Retrieve Context
-            let course = input.get_course(course_id);
-            let room = input.get_room(room_id);
-
-            // 2. Check Capacity (Hard Constraint)
-            if room.capacity < course.capacity_needed(&input.groups) {
-                penalty += HARD_CONSTRAINT;
-            }
-
-            // 3. Check Room Type (Hard Constraint)
-            if course.required_lab && !room.is_laboratory {
-                penalty += HARD_CONSTRAINT;
-            }
+            // 1-3. Check Capacity, Room Type and Unavailability (Hard Constraints)
+            room_penalty(input, session.course_id, *assignment, sink);
 
             // 4. Check Double Booking (Hard Constraint)
-            if grid[day as usize][slot as usize][room_id].is_some() {
-                penalty += HARD_CONSTRAINT;
+            if let Some(occupant) = grid[day as usize][slot as usize][room_id] {
+                sink.add(ConstraintKind::RoomDoubleBooking, u64::from(input.weights.points(ConstraintKind::RoomDoubleBooking)), |kind| {
+                    Violation::new(kind)
+                        .with_courses(input, [self.sessions[occupant].course_id, session.course_id])
+                        .with_rooms(input, [room_id])
+                        .at(day, Some(slot))
+                });
             } else {
-                // Mark the room as occupied by this course
-                grid[day as usize][slot as usize][room_id] = Some(course_id);
+                // Mark the room as occupied by this session
+                grid[day as usize][slot as usize][room_id] = Some(session_id);
             }
         }
-        penalty
     }
 
     /// Checks all Student Group constraints (Collisions, Teleportation, Gaps).
     ///
-    /// This function iterates through every student group to reconstruct their personal weekly schedule.
-    /// It then identifies three types of issues:
+    /// This function iterates through every student group to reconstruct their personal weekly schedule
+    /// from the sessions of all the courses it attends.
+    /// It then identifies four types of issues:
     /// 1. **Student Collision (Hard):** The group is assigned two courses at the same time.
-    /// 2. **Teleportation (Hard):** The group has back-to-back classes in different buildings.
+    /// 2. **Teleportation (Hard):** The group has back-to-back classes in buildings too far apart
+    ///    for the break (or any two buildings, without `travel_times`). Closer ones cost soft Travel Time.
     /// 3. **Gaps (Soft):** The group has empty hours between classes during the day.
+    /// 4. **Lunch Break (Soft or Hard):** The group has no free slot in the lunch window (`weights.lunch_slots`).
     ///
     /// # Returns
     /// The combined penalty for all groups.
-    pub fn gap_teleportation_check<T, F>(&self, input: &TimetableInput, list_of_items: &[T], get_id: F) -> u32 
+    pub fn gap_teleportation_check<T, F>(&self, input: &TimetableInput, list_of_items: &[T], get_id: F) -> Score 
     where
+        T: Attendee,
         F: Fn(&T) -> std::iter::Copied<std::slice::Iter<'_, usize>>,{
-        let mut penalty: u32 = 0;
+        let mut penalty = Score::ZERO;
+        self.gap_teleportation_into(input, list_of_items, get_id, &mut penalty);
+        penalty
+    }
 
-        for item in list_of_items{
-            // Stack-allocated grid to track this specific group's week.
-            // [Day][Slot] -> Option<RoomID>
-            let mut grid_teleportation = [[None::<usize>; 6]; 5];
+    fn gap_teleportation_into<T, F, S>(&self, input: &TimetableInput, list_of_items: &[T], get_id: F, sink: &mut S)
+    where
+        T: Attendee,
+        F: Fn(&T) -> std::iter::Copied<std::slice::Iter<'_, usize>>,
+        S: PenaltySink,{
+        let sessions_by_course = self.sessions_by_course(input.courses.len());
+
+        for (index, item) in list_of_items.iter().enumerate(){
+            let attendee = T::attendee_id(index);
+            // Grid to track this specific group's week.
+            // [Day][Slot] -> Option<SessionID>
+            let mut grid_teleportation = vec![vec![None::<usize>; input.week.slot_count()]; input.week.day_count()];
             
-            // Phase 1: Fill the grid and check for instant collisions/teleportation
+            // Phase 1: Fill the grid and check for instant collisions
             for course_id in get_id(item) {
-                penalty += self.check_penalty_teleportation(&mut grid_teleportation, input, &course_id);
+                for &session_id in &sessions_by_course[course_id] {
+                    self.place_session(input, &mut grid_teleportation, attendee, session_id, sink);
+                }
             }
             
-            // Phase 2: Scan the filled grid for time gaps
-            for day in &grid_teleportation {
-                penalty += self.check_in_day(day);
+            // Phase 2: Scan the filled grid for teleportation and time gaps
+            for (day, sessions) in grid_teleportation.iter().enumerate() {
+                day_penalty(input, self, attendee, day as u32, sessions, sink);
             }
         }
-        penalty
     }
 
-    /// Helper that places a single course into a Group's schedule and checks immediate constraints.
+    /// Helper that places a single session into a Group's schedule.
+    ///
+    /// When two sessions share a slot, the one with the lowest ID keeps the cell,
+    /// so the result does not depend on the order of the course list.
     ///
     /// # Penalties Applied
-    /// * **+10,000 (Student Collision):** If the slot is already occupied.
-    /// * **+10,000 (Teleportation):** If the adjacent slots (before/after) have classes in different buildings.
+    /// * **+1 hard (Student Collision):** If the slot is already occupied.
     ///
     /// # Arguments
-    /// * `grid_teleportation` - The mutable 5x6 grid for the current group.
-    /// * `course_id` - The ID of the course being placed.
-    fn check_penalty_teleportation(
-        &self, 
-        grid_teleportation: &mut [[Option<usize>; 6]; 5], 
-        input: &TimetableInput, 
-        course_id: &usize
-    ) -> u32 {
-        let mut penalty = 0;
-        
-        let (day, slot, room_id) = self.assignments[*course_id];
-        
-        // Check 1: Student Collision (Hard)
-        if grid_teleportation[day as usize][slot as usize].is_some() {
-            penalty += HARD_CONSTRAINT;
-        } else {
-            // Place the course in the grid
-            grid_teleportation[day as usize][slot as usize] = Some(room_id);
-            
-            // Check 2: Teleportation (Look Backwards)
-            if slot != 0 {
-                penalty += self.check_adjacent(room_id, &grid_teleportation[day as usize][slot as usize - 1], &input);
+    /// * `grid_teleportation` - The mutable [Day][Slot] grid for the current group.
+    /// * `attendee` - The Group or Teacher owning the grid.
+    /// * `session_id` - The ID of the session being placed.
+    fn place_session<S: PenaltySink>(
+        &self,
+        input: &TimetableInput,
+        grid_teleportation: &mut [Vec<Option<usize>>],
+        attendee: AttendeeId,
+        session_id: usize,
+        sink: &mut S,
+    ) {
+        let (day, slot, _) = self.assignments[session_id];
+        let cell = &mut grid_teleportation[day as usize][slot as usize];
+
+        match cell {
+            // Check: Student Collision (Hard)
+            Some(other) => {
+                let courses = [self.sessions[*other].course_id, self.sessions[session_id].course_id];
+                *other = (*other).min(session_id);
+                let kind = match attendee {
+                    AttendeeId::Group(_) => ConstraintKind::GroupCollision,
+                    AttendeeId::Teacher(_) => ConstraintKind::TeacherCollision,
+                };
+                sink.add(kind, u64::from(input.weights.points(kind)), |kind| collision_violation(input, attendee, kind, courses, day, slot));
             }
-            // Check 3: Teleportation (Look Forwards)
-            let slot_after = slot + 1;
-            if slot_after <= 5 {
-                penalty += self.check_adjacent(room_id, &grid_teleportation[day as usize][slot_after as usize], &input);
+            None => {
+                *cell = Some(session_id);
             }
         }
-        penalty
     }
+}
+
+/// Builds the violation for two sessions of the same Group/Teacher at the same time.
+fn collision_violation(input: &TimetableInput, attendee: AttendeeId, kind: ConstraintKind, courses: [usize; 2], day: u32, slot: u32) -> Violation {
+    Violation::new(kind)
+        .with_courses(input, courses)
+        .with_attendee(input, attendee)
+        .at(day, Some(slot))
+}
+
+/// Checks whether a session of `course_id` can use the room of `assignment` at that time.
+///
+/// # Penalties Applied
+/// * **+1 hard (Capacity):** If the room is too small for all attending groups.
+/// * **+1 hard (Room Type):** If the course needs a Lab and the room is not a Lab.
+/// * **+1 hard (Unavailability):** If the room is blocked at that Day/Slot.
+pub(crate) fn room_penalty<S: PenaltySink>(input: &TimetableInput, course_id: usize, assignment: (u32, u32, usize), sink: &mut S) {
+    let (day, slot, room_id) = assignment;
+    let course = input.get_course(course_id);
+    let room = input.get_room(room_id);
+    let violation = |kind| {
+        Violation::new(kind)
+            .with_courses(input, [course_id])
+            .with_rooms(input, [room_id])
+            .at(day, Some(slot))
+    };
+
+    if room.capacity < course.capacity_needed(&input.groups) {
+        sink.add(ConstraintKind::RoomCapacity, u64::from(input.weights.points(ConstraintKind::RoomCapacity)), violation);
+    }
+    if course.required_lab && !room.is_laboratory {
+        sink.add(ConstraintKind::LabMismatch, u64::from(input.weights.points(ConstraintKind::LabMismatch)), violation);
+    }
+    if room.is_unavailable(day, slot) {
+        sink.add(ConstraintKind::RoomUnavailable, u64::from(input.weights.points(ConstraintKind::RoomUnavailable)), violation);
+    }
+}
 
+/// Minimal perturbation: penalizes `session` for leaving its place in `input.previous`.
+///
+/// # Penalties Applied
+/// * **+weight soft:** If the session is on another Day/Slot than before.
+/// * **+weight soft:** If the session is in another room than before.
+pub(crate) fn perturbation_penalty<S: PenaltySink>(input: &TimetableInput, session: Session, assignment: (u32, u32, usize), sink: &mut S) {
+    let Some(previous) = &input.previous else {
+        return;
+    };
+    let Some((old_day, old_slot, old_room)) = previous.get(session) else {
+        return;
+    };
+    let (day, slot, room_id) = assignment;
+    let moves = u64::from((old_day, old_slot) != (day, slot)) + u64::from(old_room != room_id);
 
+    let weight = moves * u64::from(previous.weight) * u64::from(input.weights.points(ConstraintKind::Perturbation));
+    sink.add(ConstraintKind::Perturbation, weight, |kind| {
+        Violation::new(kind)
+            .with_courses(input, [session.course_id])
+            .with_rooms(input, [old_room, room_id])
+            .at(day, Some(slot))
+    });
+}
 
-    /// Calculates the "Gap Penalty" for a single day.
-    ///
-    /// A "Gap" is defined as empty slots strictly *between* two classes.
-    /// Morning start times and evening end times are not penalized.
-    ///
-    /// # Scoring Rule (Soft Constraint)
-    /// * 1 Slot (2h) gap: **20 points**
-    /// * 2 Slot (4h) gap: **15 points**
-    /// * 3 Slot (6h) gap: **10 points**
-    /// * 4+ Slot (8h+) gap: **5 points**
-    fn check_in_day(&self, day: &[Option<usize>; 6]) -> u32 {
-        let mut slot: usize = 0;
-        let mut penalty = 0;
-        let mut gap_size = 0;
+/// Scores one session of course `course_id`, taught by teacher `teacher_id`, taking place at `day` / `slot`.
+/// See [`Schedule::teacher_availability_check`] for the rules.
+pub(crate) fn teacher_slot_penalty<S: PenaltySink>(input: &TimetableInput, teacher_id: usize, course_id: usize, day: u32, slot: u32, sink: &mut S) {
+    let teacher = &input.teachers[teacher_id];
+    let violation = |kind| {
+        Violation::new(kind)
+            .with_courses(input, [course_id])
+            .with_attendee(input, AttendeeId::Teacher(teacher_id))
+            .at(day, Some(slot))
+    };
+
+    if teacher.unavailable.contains(&(day, slot)) {
+        sink.add(ConstraintKind::TeacherUnavailable, u64::from(input.weights.points(ConstraintKind::TeacherUnavailable)), violation);
+    }
+    if teacher.undesired.contains(&(day, slot)) {
+        sink.add(ConstraintKind::TeacherUndesiredSlot, u64::from(input.weights.points(ConstraintKind::TeacherUndesiredSlot)), violation);
+    }
+    if !teacher.preferred.is_empty() && !teacher.preferred.contains(&(day, slot)) {
+        sink.add(ConstraintKind::TeacherOutsidePreferred, u64::from(input.weights.points(ConstraintKind::TeacherOutsidePreferred)), violation);
+    }
+}
+
+/// Scores one day of teacher `teacher_id` against its `max_hours_per_day` and `max_consecutive_slots`.
+/// `day_sessions` is its day as in [`day_penalty`]. See [`Schedule::teacher_load_check`] for the rules.
+pub(crate) fn teacher_day_load<S: PenaltySink>(input: &TimetableInput, teacher_id: usize, day: u32, day_sessions: &[Option<usize>], sink: &mut S) {
+    let limits = input.limits_of(teacher_id);
+    let violation = |kind| Violation::new(kind).with_attendee(input, AttendeeId::Teacher(teacher_id)).at(day, None);
 
-        // 1. Skip morning emptiness (Student hasn't arrived yet)
-        while slot < 6 && day[slot] == None {
-            slot += 1;
+    if let Some(max_hours) = limits.max_hours_per_day {
+        let allowed = u64::from(max_hours) * 60 / u64::from(input.week.slot_minutes);
+        let taught = day_sessions.iter().filter(|session| session.is_some()).count() as u64;
+        let points = u64::from(input.weights.points(ConstraintKind::TeacherDailyHours));
+        sink.add(ConstraintKind::TeacherDailyHours, taught.saturating_sub(allowed) * points, violation);
+    }
+    if let Some(max_slots) = limits.max_consecutive_slots {
+        let points = u64::from(input.weights.points(ConstraintKind::TeacherConsecutiveSlots));
+        for run in day_sessions.split(Option::is_none) {
+            let excess = (run.len() as u64).saturating_sub(u64::from(max_slots));
+            sink.add(ConstraintKind::TeacherConsecutiveSlots, excess * points, violation);
         }
-        let start = slot as u32;
-        let mut end = slot as u32;
-        // 2. Scan the "Active Day"
-        while slot < 6 {
-            if day[slot].is_some() {
-                // We found a class. If we were tracking a gap, finalize it.
-                if gap_size != 0 {
-                    penalty += match gap_size {
-                        1 => 20, // 2 hours
-                        2 => 15, // 4 hours
-                        3 => 10, // 6 hours
-                        _ => 5,  // 8+ hours
-                    };
-                }
-                end = slot as u32;
-                gap_size = 0; // Reset gap counter
-            } else {
-                // We found an empty slot within the active day
-                gap_size += 1;
-            }
-            slot += 1;
+    }
+}
+
+/// Scores the week of teacher `teacher_id`, who teaches on `busy_days` days, against its `min_free_days`.
+pub(crate) fn teacher_week_load<S: PenaltySink>(input: &TimetableInput, teacher_id: usize, busy_days: usize, sink: &mut S) {
+    let Some(min_free_days) = input.limits_of(teacher_id).min_free_days else {
+        return;
+    };
+    let free_days = input.week.day_count().saturating_sub(busy_days) as u64;
+    let missing = u64::from(min_free_days).saturating_sub(free_days);
+    let points = u64::from(input.weights.points(ConstraintKind::TeacherFreeDays));
+
+    sink.add(ConstraintKind::TeacherFreeDays, missing * points, |kind| {
+        Violation::new(kind).with_attendee(input, AttendeeId::Teacher(teacher_id))
+    });
+}
+
+/// Scores one day of a Group's (or Teacher's) schedule.
+///
+/// `day_sessions` holds the session placed in every slot (`None` when the slot is free).
+/// Adds the teleportation / travel time penalty for every pair of back-to-back classes
+/// and the gap / long day penalty from [`check_in_day`].
+pub(crate) fn day_penalty<S: PenaltySink>(
+    input: &TimetableInput,
+    schedule: &Schedule,
+    attendee: AttendeeId,
+    day: u32,
+    day_sessions: &[Option<usize>],
+    sink: &mut S,
+) {
+    let room_of = |session: &Option<usize>| session.map(|s| schedule.assignments[s].2);
+
+    for (slot, pair) in day_sessions.windows(2).enumerate() {
+        if let Some(current) = pair[1] {
+            let current_room = schedule.assignments[current].2;
+            let Some((kind, weight)) = check_adjacent(current_room, &room_of(&pair[0]), input) else {
+                continue;
+            };
+            sink.add(kind, weight, |kind| {
+                let previous = pair[0].expect("teleportation needs two classes");
+                Violation::new(kind)
+                    .with_courses(input, [schedule.sessions[previous].course_id, schedule.sessions[current].course_id])
+                    .with_rooms(input, [schedule.assignments[previous].2, current_room])
+                    .with_attendee(input, attendee)
+                    .at(day, Some(slot as u32 + 1))
+            });
         }
-        let excess: u32 = end-start+1;
-        if excess>4{
-            penalty += (excess-4)*(excess-4)*50;
+    }
+    check_in_day(day_sessions, &input.weights, sink, |kind| Violation::new(kind).with_attendee(input, attendee).at(day, None));
+}
+
+/// Calculates the "Gap Penalty" for a single day.
+///
+/// A "Gap" is defined as empty slots strictly *between* two classes.
+/// Morning start times and evening end times are not penalized.
+///
+/// # Scoring Rule (Soft Constraint, default `weights.gap`)
+/// * 1 Slot (2h) gap: **20 points**
+/// * 2 Slot (4h) gap: **15 points**
+/// * 3 Slot (6h) gap: **10 points**
+/// * 4+ Slot (8h+) gap: **5 points**
+///
+/// A day spanning more than `weights.compact_day_slots` (default 4) slots also
+/// pays `(span - 4)^2 * 50` (Long Day, see [`Weights::long_day_points`]).
+///
+/// When `weights.lunch_slots` is set, a day with every lunch slot taken pays
+/// `weights.lunch_break` on `weights.lunch_break_level` (Lunch Break, soft by default).
+fn check_in_day<S, V>(day: &[Option<usize>], weights: &Weights, sink: &mut S, violation: V)
+where
+    S: PenaltySink,
+    V: Fn(ConstraintKind) -> Violation,
+{
+    let mut slot: usize = 0;
+    let mut gap_size = 0;
+
+    // 1. Skip morning emptiness (Student hasn't arrived yet)
+    while slot < day.len() && day[slot].is_none() {
+        slot += 1;
+    }
+    let start = slot as u32;
+    let mut end = slot as u32;
+    // 2. Scan the "Active Day"
+    while slot < day.len() {
+        if day[slot].is_some() {
+            // We found a class. If we were tracking a gap, finalize it.
+            if gap_size != 0 {
+                sink.add(ConstraintKind::Gap, u64::from(weights.gap_points(gap_size)), &violation);
+            }
+            end = slot as u32;
+            gap_size = 0; // Reset gap counter
+        } else {
+            // We found an empty slot within the active day
+            gap_size += 1;
         }
-        penalty 
+        slot += 1;
     }
+    let span: u32 = end-start+1;
+    sink.add(ConstraintKind::LongDay, weights.long_day_points(span), &violation);
 
-    /// Checks if moving between `current_room` and `adjacent_room` is possible.
-    ///
-    /// # Returns
-    /// * **10,000:** If the rooms are in different buildings (Teleportation).
-    /// * **0:** If the rooms are in the same building, or if `adjacent_room` is None.
-    fn check_adjacent(&self, current_room: usize, adiecent_room: &Option<usize>, input: &TimetableInput) -> u32 {
-        let penalty = match adiecent_room {
-            None => return 0,
-            Some(t) => {
-                //This checks if the rooms are in a different building
-                if input.rooms[*t].building_id != input.rooms[current_room].building_id {
-                    HARD_CONSTRAINT
-                } else {
-                    0
-                }
-            },
-        };
-        penalty
+    // 3. Lunch: one slot of the window must be free
+    if weights.misses_lunch(|slot| day.get(slot as usize).is_some_and(Option::is_some)) {
+        let weight = weights.points(ConstraintKind::LunchBreak);
+        sink.add_at(ConstraintKind::LunchBreak, weights.lunch_break_level, u64::from(weight), &violation);
     }
+}
 
+/// Checks if moving between `current_room` and `adjacent_room` is possible.
+///
+/// # Returns
+/// * **Teleportation (hard, default 1):** If the rooms are in different buildings and
+///   `input.travel_times` has no entry for them, or the trip takes longer than `week.break_minutes`.
+/// * **Travel Time (soft, default 1 per minute):** If the trip fits in the break.
+/// * **None:** If the rooms are in the same building, or if `adjacent_room` is None.
+fn check_adjacent(current_room: usize, adiecent_room: &Option<usize>, input: &TimetableInput) -> Option<(ConstraintKind, u64)> {
+    let building = input.rooms[(*adiecent_room)?].building_id;
+    let current_building = input.rooms[current_room].building_id;
+    //This checks if the rooms are in a different building
+    if building == current_building {
+        return None;
+    }
+    match input.travel_minutes(building, current_building) {
+        Some(minutes) if minutes <= input.week.break_minutes => {
+            Some((ConstraintKind::TravelTime, u64::from(minutes) * u64::from(input.weights.points(ConstraintKind::TravelTime))))
+        }
+        _ => Some((ConstraintKind::Teleportation, u64::from(input.weights.points(ConstraintKind::Teleportation)))),
+    }
 }
@@ -0,0 +1,140 @@
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::iter::Sum;
+use std::ops::{Add, AddAssign, Sub, SubAssign};
+
+/// Weight of one hard point in [`Score::worsening_from`].
+const HARD_ENERGY: f64 = 100000.0;
+/// Weight of one medium point in [`Score::worsening_from`].
+const MEDIUM_ENERGY: f64 = 1000.0;
+
+/// The level of the score a constraint counts towards.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Level {
+    /// Must hold for the schedule to be usable.
+    Hard,
+    /// Weighs more than any amount of soft penalty, less than one hard point.
+    Medium,
+    /// Quality of an otherwise usable schedule.
+    Soft,
+}
+
+impl fmt::Display for Level {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Level::Hard => write!(f, "hard"),
+            Level::Medium => write!(f, "medium"),
+            Level::Soft => write!(f, "soft"),
+        }
+    }
+}
+
+/// The penalty of a schedule, split by [`Level`]. Lower is better.
+///
+/// Scores are compared lexicographically: one hard point is worse than any
+/// number of medium or soft points, so soft penalties can never outweigh a
+/// broken hard constraint. Each part is a `u64`, so large inputs do not overflow.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+pub struct Score {
+    pub hard: u64,
+    pub medium: u64,
+    pub soft: u64,
+}
+
+impl Score {
+    /// A schedule that breaks no constraint at all.
+    pub const ZERO: Score = Score { hard: 0, medium: 0, soft: 0 };
+
+    /// `points` on the given level.
+    pub fn of(level: Level, points: u64) -> Self {
+        match level {
+            Level::Hard => Score { hard: points, ..Score::ZERO },
+            Level::Medium => Score { medium: points, ..Score::ZERO },
+            Level::Soft => Score { soft: points, ..Score::ZERO },
+        }
+    }
+
+    pub fn hard(points: u32) -> Self {
+        Self::of(Level::Hard, u64::from(points))
+    }
+
+    pub fn medium(points: u32) -> Self {
+        Self::of(Level::Medium, u64::from(points))
+    }
+
+    pub fn soft(points: u32) -> Self {
+        Self::of(Level::Soft, u64::from(points))
+    }
+
+    /// Returns true if every hard constraint holds.
+    pub fn is_feasible(&self) -> bool {
+        self.hard == 0
+    }
+
+    pub fn is_zero(&self) -> bool {
+        *self == Score::ZERO
+    }
+
+    /// How much worse this score is than `current`, for the acceptance probability of annealing.
+    ///
+    /// Only the first level on which the two differ counts (a hard point as
+    /// 100000, a medium point as 1000, a soft point as 1), like in the comparison
+    /// itself. 0 if this score is not worse.
+    pub fn worsening_from(&self, current: Score) -> f64 {
+        let difference = |new: u64, old: u64| new as f64 - old as f64;
+        if *self <= current {
+            0.0
+        } else if self.hard != current.hard {
+            difference(self.hard, current.hard) * HARD_ENERGY
+        } else if self.medium != current.medium {
+            difference(self.medium, current.medium) * MEDIUM_ENERGY
+        } else {
+            difference(self.soft, current.soft)
+        }
+    }
+}
+
+impl Add for Score {
+    type Output = Score;
+
+    fn add(self, other: Score) -> Score {
+        Score { hard: self.hard + other.hard, medium: self.medium + other.medium, soft: self.soft + other.soft }
+    }
+}
+
+impl AddAssign for Score {
+    fn add_assign(&mut self, other: Score) {
+        *self = *self + other;
+    }
+}
+
+impl Sub for Score {
+    type Output = Score;
+
+    /// Takes away a part of this score, e.g. the penalty a session brought.
+    ///
+    /// # Panics
+    /// Panics (in debug builds) if a level would go below 0.
+    fn sub(self, other: Score) -> Score {
+        Score { hard: self.hard - other.hard, medium: self.medium - other.medium, soft: self.soft - other.soft }
+    }
+}
+
+impl SubAssign for Score {
+    fn sub_assign(&mut self, other: Score) {
+        *self = *self - other;
+    }
+}
+
+impl Sum for Score {
+    fn sum<I: Iterator<Item = Score>>(scores: I) -> Score {
+        scores.fold(Score::ZERO, Score::add)
+    }
+}
+
+impl fmt::Display for Score {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}hard/{}medium/{}soft", self.hard, self.medium, self.soft)
+    }
+}
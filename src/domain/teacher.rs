@@ -1,8 +1,49 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Teacher{
     pub id: usize,
     pub name: String,
     pub course_id: Vec<usize>,
+
+    /// (Day, Slot) pairs in which the teacher cannot teach (Hard Constraint).
+    #[serde(default)]
+    pub unavailable: Vec<(u32, u32)>,
+
+    /// (Day, Slot) pairs the teacher would like to teach in.
+    /// When not empty, every session outside of them is a Soft Constraint violation.
+    #[serde(default)]
+    pub preferred: Vec<(u32, u32)>,
+
+    /// (Day, Slot) pairs the teacher would rather not teach in (Soft Constraint).
+    #[serde(default)]
+    pub undesired: Vec<(u32, u32)>,
+
+    /// Teaching load limits of this teacher (`max_hours_per_day`, `max_consecutive_slots`,
+    /// `min_free_days` next to the other fields). The ones not set come from `TimetableInput::teacher_limits`.
+    #[serde(default, flatten)]
+    pub limits: TeacherLimits,
+}
+
+/// How much a teacher may teach (Hard Constraints). `None` means no limit.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct TeacherLimits {
+    /// Most teaching hours in one day.
+    pub max_hours_per_day: Option<u32>,
+    /// Most slots taught back to back.
+    pub max_consecutive_slots: Option<u32>,
+    /// Fewest days of the week without any class.
+    pub min_free_days: Option<u32>,
+}
+
+impl TeacherLimits {
+    /// These limits, with the ones not set taken from `defaults`.
+    pub fn or(self, defaults: TeacherLimits) -> TeacherLimits {
+        TeacherLimits {
+            max_hours_per_day: self.max_hours_per_day.or(defaults.max_hours_per_day),
+            max_consecutive_slots: self.max_consecutive_slots.or(defaults.max_consecutive_slots),
+            min_free_days: self.min_free_days.or(defaults.min_free_days),
+        }
+    }
 }
@@ -0,0 +1,22 @@
+use serde::{Deserialize, Serialize};
+
+/// How long it takes to get from one building to another (and back).
+///
+/// Listed in the optional `travel_times` section of the input file, using the
+/// `building_id`s of the rooms. Buildings without an entry count as too far
+/// apart for back-to-back classes (Teleportation).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TravelTime {
+    pub from_building: usize,
+    pub to_building: usize,
+    /// Travel time in minutes, in either direction.
+    pub minutes: u32,
+}
+
+impl TravelTime {
+    /// Returns true if this entry is about the two buildings, in any order.
+    pub fn connects(&self, building: usize, other_building: usize) -> bool {
+        (self.from_building, self.to_building) == (building, other_building)
+            || (self.from_building, self.to_building) == (other_building, building)
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// The shape of a teaching week: which days exist and how each day is cut into slots.
+///
+/// Every grid in the solver (`[Day][Slot]`) is sized from this struct, and the
+/// output uses its labels. When the input file has no `week` section, the
+/// classic Mon-Fri week with six 2-hour slots (08:00-20:00) is used.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct WeekGrid {
+    /// Labels of the teaching days, in order (e.g. "Mon", "Sat").
+    pub days: Vec<String>,
+
+    /// Labels of the slots of one day, in order (e.g. "08:00-09:30").
+    pub slots: Vec<String>,
+
+    /// Length of one slot in minutes (120 for 2-hour blocks, 90 for 1.5-hour periods).
+    /// Used to turn `Course::required_hours` into a number of sessions.
+    pub slot_minutes: u32,
+
+    /// Minutes of break between two consecutive slots, to get to the next room.
+    /// Back-to-back classes whose buildings are further apart than this break the Teleportation constraint.
+    pub break_minutes: u32,
+}
+
+impl WeekGrid {
+    /// Number of teaching days in the week.
+    pub fn day_count(&self) -> usize {
+        self.days.len()
+    }
+
+    /// Number of slots in one day.
+    pub fn slot_count(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Number of (Day, Slot) cells in the week.
+    pub fn cell_count(&self) -> usize {
+        self.day_count() * self.slot_count()
+    }
+}
+
+impl Default for WeekGrid {
+    fn default() -> Self {
+        Self {
+            days: ["Mon", "Tue", "Wed", "Thu", "Fri"].map(String::from).to_vec(),
+            slots: [
+                "08:00-10:00",
+                "10:00-12:00",
+                "12:00-14:00",
+                "14:00-16:00",
+                "16:00-18:00",
+                "18:00-20:00",
+            ].map(String::from).to_vec(),
+            slot_minutes: 120,
+            break_minutes: 0,
+        }
+    }
+}
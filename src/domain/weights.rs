@@ -0,0 +1,133 @@
+use crate::domain::report::ConstraintKind;
+use crate::domain::score::Level;
+use serde::{Deserialize, Serialize};
+
+/// How many points each broken constraint costs, on its level of the score.
+///
+/// Read from the `weights` section of the input file, or from the `[weights]`
+/// table of `config.toml`, whose keys override the input's one by one. Missing fields
+/// keep their default; a weight of 0 or an entry in `disabled` switches a constraint off.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Weights {
+    pub room_capacity: u32,
+    pub lab_mismatch: u32,
+    pub room_unavailable: u32,
+    pub room_double_booking: u32,
+    pub group_collision: u32,
+    pub teacher_collision: u32,
+    pub teleportation: u32,
+    pub teacher_unavailable: u32,
+    /// Points per slot taught over `max_hours_per_day`.
+    pub teacher_daily_hours: u32,
+    /// Points per slot over `max_consecutive_slots` in a run of back-to-back classes.
+    pub teacher_consecutive_slots: u32,
+    /// Points per free day missing to `min_free_days`.
+    pub teacher_free_days: u32,
+    /// Points per minute of travel between back-to-back classes in different
+    /// buildings, when the break is long enough (see `travel_times`).
+    pub travel_time: u32,
+    /// Points for a gap of 1, 2, 3, ... free slots between two classes;
+    /// the last entry is used for every longer gap.
+    pub gap: Vec<u32>,
+    /// A day spanning more than `compact_day_slots` pays `(span - compact_day_slots)^2 * long_day`.
+    pub long_day: u32,
+    /// Number of slots from first to last class that a day may span for free.
+    pub compact_day_slots: u32,
+    /// Slots of the lunch window (e.g. `[2, 3]` for 12:00-16:00). Every group and teacher
+    /// with classes must have one of them free each day. No lunch break is checked when empty.
+    pub lunch_slots: Vec<u32>,
+    /// Points for a day without a free slot in the lunch window.
+    pub lunch_break: u32,
+    /// Whether a missed lunch break is a soft (default) or a hard violation.
+    pub lunch_break_level: Level,
+    pub teacher_undesired_slot: u32,
+    pub teacher_outside_preferred: u32,
+    /// Constraints that are not scored at all (e.g. `["Gap", "LongDay"]`).
+    pub disabled: Vec<ConstraintKind>,
+}
+
+impl Default for Weights {
+    fn default() -> Self {
+        Self {
+            room_capacity: 1,
+            lab_mismatch: 1,
+            room_unavailable: 1,
+            room_double_booking: 1,
+            group_collision: 1,
+            teacher_collision: 1,
+            teleportation: 1,
+            teacher_unavailable: 1,
+            teacher_daily_hours: 1,
+            teacher_consecutive_slots: 1,
+            teacher_free_days: 1,
+            travel_time: 1,
+            gap: vec![20, 15, 10, 5],
+            long_day: 50,
+            compact_day_slots: 4,
+            lunch_slots: Vec::new(),
+            lunch_break: 50,
+            lunch_break_level: Level::Soft,
+            teacher_undesired_slot: 30,
+            teacher_outside_preferred: 10,
+            disabled: Vec::new(),
+        }
+    }
+}
+
+impl Weights {
+    pub fn is_enabled(&self, kind: ConstraintKind) -> bool {
+        !self.disabled.contains(&kind)
+    }
+
+    /// Points for one violation of `kind` (0 when it is switched off).
+    ///
+    /// For `TravelTime` these are the points per minute of travel. For `Gap` and
+    /// `LongDay` this is the factor of the shortest case, see [`Self::gap_points`]
+    /// and [`Self::long_day_points`]. `Perturbation` is weighted by
+    /// `perturbation_weight`, so it only gets 1 (or 0) here.
+    pub fn points(&self, kind: ConstraintKind) -> u32 {
+        if !self.is_enabled(kind) {
+            return 0;
+        }
+        match kind {
+            ConstraintKind::RoomCapacity => self.room_capacity,
+            ConstraintKind::LabMismatch => self.lab_mismatch,
+            ConstraintKind::RoomUnavailable => self.room_unavailable,
+            ConstraintKind::RoomDoubleBooking => self.room_double_booking,
+            ConstraintKind::GroupCollision => self.group_collision,
+            ConstraintKind::TeacherCollision => self.teacher_collision,
+            ConstraintKind::Teleportation => self.teleportation,
+            ConstraintKind::TeacherUnavailable => self.teacher_unavailable,
+            ConstraintKind::TeacherDailyHours => self.teacher_daily_hours,
+            ConstraintKind::TeacherConsecutiveSlots => self.teacher_consecutive_slots,
+            ConstraintKind::TeacherFreeDays => self.teacher_free_days,
+            ConstraintKind::TravelTime => self.travel_time,
+            ConstraintKind::Gap => self.gap_points(1),
+            ConstraintKind::LunchBreak => self.lunch_break,
+            ConstraintKind::LongDay => self.long_day,
+            ConstraintKind::TeacherUndesiredSlot => self.teacher_undesired_slot,
+            ConstraintKind::TeacherOutsidePreferred => self.teacher_outside_preferred,
+            ConstraintKind::Perturbation => 1,
+        }
+    }
+
+    /// Points for a gap of `gap_size` free slots between two classes.
+    pub fn gap_points(&self, gap_size: usize) -> u32 {
+        if !self.is_enabled(ConstraintKind::Gap) || gap_size == 0 {
+            return 0;
+        }
+        self.gap.get(gap_size - 1).or(self.gap.last()).copied().unwrap_or(0)
+    }
+
+    /// Returns true if a day with classes in the `occupied` slots has no free slot in the lunch window.
+    pub fn misses_lunch(&self, occupied: impl Fn(u32) -> bool) -> bool {
+        !self.lunch_slots.is_empty() && self.lunch_slots.iter().all(|&slot| occupied(slot))
+    }
+
+    /// Points for a day whose classes span `span` slots, first to last.
+    pub fn long_day_points(&self, span: u32) -> u64 {
+        let excess = u64::from(span.saturating_sub(self.compact_day_slots));
+        excess * excess * u64::from(self.points(ConstraintKind::LongDay))
+    }
+}
@@ -0,0 +1,169 @@
+use crate::domain::{
+    input_wrapper::TimetableInput, previous_schedule::PreviousSchedule, report::PenaltyReport, schedule::Schedule,
+    score::Score,
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::error::Error;
+use std::fs::File;
+use std::io::{BufReader, BufWriter};
+
+/// A solved timetable in machine-readable form.
+///
+/// Unlike `Schedule`, which works on normalized vector indices, every ID here
+/// is the original "Database ID" from the input file.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ScheduleExport {
+    /// Total penalty of the schedule (all zero means every constraint is satisfied).
+    pub penalty: Score,
+    pub sessions: Vec<SessionExport>,
+}
+
+/// One scheduled session of a course.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionExport {
+    pub course_id: usize,
+    /// Which meeting of the course this is (0 for the first one).
+    pub session_index: u32,
+    pub group_ids: Vec<usize>,
+    pub teacher_id: usize,
+    pub room_id: usize,
+    /// Index of the day in the week grid.
+    pub day: u32,
+    /// Index of the slot in the day.
+    pub slot: u32,
+    /// Label of the day (e.g. "Mon"), for readability only.
+    #[serde(default)]
+    pub day_name: String,
+    /// Label of the slot (e.g. "08:00-10:00"), for readability only.
+    #[serde(default)]
+    pub slot_label: String,
+}
+
+/// Converts `schedule` to original IDs.
+///
+/// # Arguments
+/// * `input` - The normalized input the schedule was solved for.
+pub fn export_schedule(schedule: &Schedule, input: &TimetableInput) -> ScheduleExport {
+    let sessions = schedule.sessions.iter().zip(&schedule.assignments)
+        .map(|(session, &(day, slot, room_id))| {
+            let course = input.get_course(session.course_id);
+            SessionExport {
+                course_id: course.id,
+                session_index: session.index,
+                group_ids: course.group_ids.iter().map(|&g| input.groups[g].id).collect(),
+                teacher_id: course.professor_id,
+                room_id: input.get_room(room_id).id,
+                day,
+                slot,
+                day_name: input.week.days[day as usize].clone(),
+                slot_label: input.week.slots[slot as usize].clone(),
+            }
+        })
+        .collect();
+
+    ScheduleExport { penalty: schedule.calculate_penalty(input), sessions }
+}
+
+/// Rebuilds a `Schedule` from an export, mapping original IDs back to indices.
+///
+/// The sessions are laid out like `Schedule::sessions_for`, so the result can be
+/// handed straight to the solver.
+///
+/// # Errors
+/// Fails if a course or room ID is unknown, if a day/slot is outside the week,
+/// or if a session is missing or listed twice.
+pub fn import_schedule(export: &ScheduleExport, input: &TimetableInput) -> Result<Schedule, Box<dyn Error>> {
+    let course_index: HashMap<usize, usize> = input.courses.iter().enumerate().map(|(i, c)| (c.id, i)).collect();
+    let room_index: HashMap<usize, usize> = input.rooms.iter().enumerate().map(|(i, r)| (r.id, i)).collect();
+
+    let sessions = Schedule::sessions_for(input);
+    let session_index: HashMap<(usize, u32), usize> = sessions.iter().enumerate()
+        .map(|(i, s)| ((s.course_id, s.index), i))
+        .collect();
+    let mut assignments: Vec<Option<(u32, u32, usize)>> = vec![None; sessions.len()];
+
+    for entry in &export.sessions {
+        let course_id = *course_index.get(&entry.course_id)
+            .ok_or_else(|| format!("unknown course ID {}", entry.course_id))?;
+        let room_id = *room_index.get(&entry.room_id)
+            .ok_or_else(|| format!("unknown room ID {}", entry.room_id))?;
+        if entry.day as usize >= input.week.day_count() || entry.slot as usize >= input.week.slot_count() {
+            return Err(format!("course {} is outside the week (day {}, slot {})", entry.course_id, entry.day, entry.slot).into());
+        }
+
+        let session_id = *session_index.get(&(course_id, entry.session_index))
+            .ok_or_else(|| format!("course {} has no session {}", entry.course_id, entry.session_index))?;
+        if assignments[session_id].replace((entry.day, entry.slot, room_id)).is_some() {
+            return Err(format!("session {} of course {} is listed twice", entry.session_index, entry.course_id).into());
+        }
+    }
+
+    let assignments = assignments.into_iter().zip(&sessions)
+        .map(|(assignment, session)| assignment.ok_or_else(|| {
+            format!("session {} of course {} is missing", session.index, input.get_course(session.course_id).id)
+        }))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    Ok(Schedule { sessions, assignments })
+}
+
+/// Maps last run's export onto a (possibly edited) input, for a warm start.
+///
+/// Unlike [`import_schedule`] this never fails: entries whose course or room no
+/// longer exists, whose session is gone or which fall outside the week are
+/// skipped, and sessions missing from the export are simply new.
+///
+/// # Arguments
+/// * `weight` - Penalty for each session moved away from its old time, and again for its old room.
+pub fn previous_schedule(export: &ScheduleExport, input: &TimetableInput, weight: u32) -> PreviousSchedule {
+    let course_index: HashMap<usize, usize> = input.courses.iter().enumerate().map(|(i, c)| (c.id, i)).collect();
+    let room_index: HashMap<usize, usize> = input.rooms.iter().enumerate().map(|(i, r)| (r.id, i)).collect();
+    let mut assignments: Vec<Vec<Option<(u32, u32, usize)>>> = input.courses.iter()
+        .map(|course| vec![None; course.sessions_needed(&input.week) as usize])
+        .collect();
+
+    for entry in &export.sessions {
+        let (Some(&course_id), Some(&room_id)) = (course_index.get(&entry.course_id), room_index.get(&entry.room_id)) else {
+            continue;
+        };
+        if entry.day as usize >= input.week.day_count() || entry.slot as usize >= input.week.slot_count() {
+            continue;
+        }
+        if let Some(assignment) = assignments[course_id].get_mut(entry.session_index as usize) {
+            *assignment = Some((entry.day, entry.slot, room_id));
+        }
+    }
+
+    PreviousSchedule { assignments, weight }
+}
+
+/// Writes `schedule` to `file_name` as pretty-printed JSON with original IDs.
+pub fn write_schedule_json(file_name: &str, schedule: &Schedule, input: &TimetableInput) -> Result<(), Box<dyn Error>> {
+    let writer = BufWriter::new(File::create(file_name)?);
+    serde_json::to_writer_pretty(writer, &export_schedule(schedule, input))?;
+    Ok(())
+}
+
+/// Loads a schedule written by [`write_schedule_json`] back into a `Schedule`.
+pub fn read_schedule_json(file_name: &str, input: &TimetableInput) -> Result<Schedule, Box<dyn Error>> {
+    let file = File::open(file_name)
+        .map_err(|e| format!("couldn't open '{}': {}", file_name, e))?;
+    let export: ScheduleExport = serde_json::from_reader(BufReader::new(file))?;
+    import_schedule(&export, input)
+}
+
+/// Loads last run's schedule from `file_name` for a warm start (see [`previous_schedule`]).
+pub fn read_previous_schedule(file_name: &str, input: &TimetableInput, weight: u32) -> Result<PreviousSchedule, Box<dyn Error>> {
+    let file = File::open(file_name)
+        .map_err(|e| format!("couldn't open '{}': {}", file_name, e))?;
+    let export: ScheduleExport = serde_json::from_reader(BufReader::new(file))?;
+    Ok(previous_schedule(&export, input, weight))
+}
+
+/// Writes a penalty breakdown to `file_name` as pretty-printed JSON.
+pub fn write_report_json(file_name: &str, report: &PenaltyReport) -> Result<(), Box<dyn Error>> {
+    let writer = BufWriter::new(File::create(file_name)?);
+    serde_json::to_writer_pretty(writer, report)?;
+    Ok(())
+}
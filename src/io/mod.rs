@@ -1,3 +1,5 @@
 pub mod read_input;
 pub mod normalize_input;
+pub mod validate_input;
 pub mod output;
+pub mod export;
@@ -12,20 +12,30 @@ use std::hash::Hash;
 ///
 /// # Returns
 /// A normalized `TimetableInput` where `course.group_ids` refers to the 
-/// actual index in the `groups` vector.
+/// actual index in the `groups` vector (and likewise for the other references,
+/// including the course and room of every pin).
 ///
 /// # Note
 /// The `input.groups[i].id` field is **NOT** changed. We keep the original ID 
 /// stored there so we can map the results back to "Real IDs" when generating 
-/// the final JSON output.    let mut group_id_to_index = HashMap::new();
+/// the final JSON output (see `export::export_schedule`).
+///
+/// # Panics
+/// Panics on the first reference to a non existent ID.
+/// Run `validate_input::validate_input` first to get the full list of problems instead.
 pub fn normalize_data(mut input: TimetableInput) ->TimetableInput{
-    let group_map = build_lookup_map(&input.groups, |g| g.id as usize);
+    let group_map = build_lookup_map(&input.groups, |g| g.id);
     update_references(&mut input.courses, &group_map, |c| &mut c.group_ids);
 
-    let course_map = build_lookup_map(&input.courses, |g| g.id as usize);
+    let course_map = build_lookup_map(&input.courses, |g| g.id);
     update_references(&mut input.groups, &course_map, |g| &mut g.courses);
     update_references(&mut input.teachers, &course_map, |t| &mut t.course_id);
 
+    let room_map = build_lookup_map(&input.rooms, |r| r.id);
+    for pin in &mut input.pinned {
+        pin.course_id = lookup(&course_map, pin.course_id);
+        pin.room_id = pin.room_id.map(|room_id| lookup(&room_map, room_id));
+    }
 
     input
 }
@@ -40,6 +50,9 @@ where
     }
     map
 }
+fn lookup(map: &HashMap<usize, usize>, db_id: usize) -> usize {
+    *map.get(&db_id).unwrap_or_else(|| panic!("Reference to a non existent ID: {}", db_id))
+}
 fn update_references<T, K, F>(items: &mut [T], map: &HashMap<K, usize>, get_vec_mut: F)
     where
         K: Eq + Hash + Copy + std::fmt::Display,
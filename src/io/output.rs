@@ -1,18 +1,12 @@
 use crate::domain::{input_wrapper::TimetableInput, schedule::Schedule};
 
 pub fn print_schedule(schedule: &Schedule, input: &TimetableInput) {
-    let days = ["Mon", "Tue", "Wed", "Thu", "Fri"];
-    let slots = [
-        "08:00-10:00",
-        "10:00-12:00",
-        "12:00-14:00",
-        "14:00-16:00",
-        "16:00-18:00",
-        "18:00-20:00",
-    ];
+    let days = &input.week.days;
+    let slots = &input.week.slots;
 
     // Column width (fixed)
     let col_width = 22; 
+    let sessions_by_course = schedule.sessions_by_course(input.courses.len());
 
     for group in &input.groups {
         println!("\n");
@@ -26,7 +20,7 @@ pub fn print_schedule(schedule: &Schedule, input: &TimetableInput) {
             print!("{:^width$} |", day, width = col_width);
         }
         println!();
-        print_separator(col_width);
+        print_separator(col_width, days.len());
 
         // 2. Rows
         for (slot_idx, time_label) in slots.iter().enumerate() {
@@ -37,16 +31,18 @@ pub fn print_schedule(schedule: &Schedule, input: &TimetableInput) {
             let mut line_a_courses = Vec::new(); // Stores course names
             let mut line_b_rooms = Vec::new();   // Stores room names
 
-            for day_idx in 0..5 {
-                // Find if there is a course for this Group at this Day/Slot
-                let match_course = group.courses.iter().find(|&&cid| {
-                    let (d, s, _) = schedule.assignments[cid];
-                    d as usize == day_idx && s as usize == slot_idx
-                });
+            for day_idx in 0..days.len() {
+                // Find if there is a session for this Group at this Day/Slot
+                let match_session = group.courses.iter()
+                    .flat_map(|&cid| &sessions_by_course[cid])
+                    .find(|&&sid| {
+                        let (d, s, _) = schedule.assignments[sid];
+                        d as usize == day_idx && s as usize == slot_idx
+                    });
 
-                if let Some(&course_id) = match_course {
-                    let course = input.get_course(course_id);
-                    let (_, _, room_id) = schedule.assignments[course_id];
+                if let Some(&session_id) = match_session {
+                    let course = input.get_course(schedule.sessions[session_id].course_id);
+                    let (_, _, room_id) = schedule.assignments[session_id];
                     let room = input.get_room(room_id);
 
                     line_a_courses.push(truncate(&course.subject_name, col_width));
@@ -72,7 +68,7 @@ pub fn print_schedule(schedule: &Schedule, input: &TimetableInput) {
             println!();
 
             // Divider between time slots
-            print_separator(col_width);
+            print_separator(col_width, days.len());
         }
     }
 }
@@ -89,7 +85,7 @@ fn truncate(s: &str, max_len: usize) -> String {
 }
 
 /// Helper: Prints the dashed line separator
-fn print_separator(col_width: usize) {
-    let total_width = 15 + 3 + (col_width + 3) * 5; // Time col + divider + 5 * (col + divider)
+fn print_separator(col_width: usize, day_count: usize) {
+    let total_width = 15 + 3 + (col_width + 3) * day_count; // Time col + divider + days * (col + divider)
     println!("{:-<1$}", "", total_width);
 }
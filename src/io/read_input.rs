@@ -3,8 +3,12 @@ use std::io::BufReader;
 use std::fs::File;
 use std::error::Error;
 
+/// Loads the raw `TimetableInput` from a JSON file.
+///
+/// A missing or unreadable file is returned as an error instead of panicking.
 pub fn read_json(file_name: &String) -> Result<TimetableInput, Box<dyn Error>>{
-    let file_json = File::open(file_name).expect("I couldnt open the file, make sure that u placed the file");
+    let file_json = File::open(file_name)
+        .map_err(|e| format!("couldn't open '{}', make sure that the file is in place: {}", file_name, e))?;
     let buf_reader = BufReader::new(file_json);
 
     let input_data: TimetableInput = serde_json::from_reader(buf_reader)?;
@@ -0,0 +1,339 @@
+use crate::domain::{course::Course, group::Group, input_wrapper::TimetableInput};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+/// One problem found in the raw input by [`validate_input`]
+/// (or in the normalized one by [`validate_room_domains`]).
+///
+/// All IDs are the original "Database IDs" from the JSON file.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ValidationError {
+    /// Two items of the same kind share an ID.
+    DuplicateId { kind: &'static str, id: usize },
+    /// An item points to an ID that does not exist.
+    DanglingReference { from: &'static str, from_id: usize, to: &'static str, to_id: usize },
+    /// `Course::professor_id` and `Teacher::course_id` disagree.
+    ProfessorMismatch { course_id: usize, teacher_id: usize },
+    /// `Course::group_ids` and `Group::courses` disagree.
+    GroupMismatch { course_id: usize, group_id: usize },
+    /// A course is attended by no group.
+    CourseWithoutGroups { course_id: usize },
+    /// A course needs a laboratory, but no room is a laboratory.
+    NoLabRoom { course_id: usize },
+    /// A course has more students than any room it could use.
+    CapacityExceedsEveryRoom { course_id: usize, needed: u32, largest: u32 },
+    /// The week has no days, no slots, or zero-length slots.
+    EmptyWeek,
+    /// No room is big enough, of the right type and open at some point of the week.
+    NoFeasibleRoom { course_id: usize },
+    /// A pin fixes a day or slot that is not in the week grid.
+    PinOutsideWeek { course_id: usize },
+    /// A pin names a session the course does not have.
+    PinnedSessionMissing { course_id: usize, session_index: u32 },
+    /// More than one pin applies to the same session.
+    DuplicatePin { course_id: usize, session_index: u32 },
+    /// Two pinned sessions are fixed to the same (Day, Slot) and share a room, a teacher or a group.
+    PinClash { course_id: usize, other_course_id: usize, day: u32, slot: u32, shared: &'static str },
+    /// A course needs more sessions than the week has (Day, Slot) cells.
+    TooManyHours { course_id: usize, required_hours: u32 },
+    /// A slot of `weights.lunch_slots` is not a slot of the week.
+    LunchSlotOutsideWeek { slot: u32 },
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::DuplicateId { kind, id } => write!(f, "Duplicate {} ID {}", kind, id),
+            Self::DanglingReference { from, from_id, to, to_id } => {
+                write!(f, "{} {} references non existent {} ID {}", from, from_id, to, to_id)
+            }
+            Self::ProfessorMismatch { course_id, teacher_id } => write!(
+                f,
+                "Course {} and teacher {} disagree on who teaches it (professor_id vs course_id)",
+                course_id, teacher_id
+            ),
+            Self::GroupMismatch { course_id, group_id } => write!(
+                f,
+                "Course {} and group {} disagree on attendance (group_ids vs courses)",
+                course_id, group_id
+            ),
+            Self::CourseWithoutGroups { course_id } => write!(f, "Course {} has no groups", course_id),
+            Self::NoLabRoom { course_id } => {
+                write!(f, "Course {} requires a laboratory, but there are no laboratory rooms", course_id)
+            }
+            Self::CapacityExceedsEveryRoom { course_id, needed, largest } => write!(
+                f,
+                "Course {} needs {} seats, but the largest suitable room has {}",
+                course_id, needed, largest
+            ),
+            Self::EmptyWeek => write!(f, "The week must have at least one day, one slot and a slot length above 0"),
+            Self::NoFeasibleRoom { course_id } => write!(
+                f,
+                "Course {} has no room that is big enough, of the right type and open during the week",
+                course_id
+            ),
+            Self::PinOutsideWeek { course_id } => {
+                write!(f, "Course {} is pinned to a day or slot outside the week", course_id)
+            }
+            Self::PinnedSessionMissing { course_id, session_index } => {
+                write!(f, "Course {} is pinned at session {}, which it does not have", course_id, session_index)
+            }
+            Self::DuplicatePin { course_id, session_index } => {
+                write!(f, "Session {} of course {} is pinned more than once", session_index, course_id)
+            }
+            Self::PinClash { course_id, other_course_id, day, slot, shared } => write!(
+                f,
+                "Pinned sessions of courses {} and {} share a {} at day {}, slot {}",
+                course_id, other_course_id, shared, day, slot
+            ),
+            Self::TooManyHours { course_id, required_hours } => {
+                write!(f, "Course {} requires {} hours, more than the week has slots for", course_id, required_hours)
+            }
+            Self::LunchSlotOutsideWeek { slot } => write!(f, "Lunch slot {} is outside the week", slot),
+        }
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// **Checks the raw input before it is normalized.**
+///
+/// Unlike `normalize_data`, which panics on the first bad reference, this pass
+/// keeps going and collects every problem it finds.
+///
+/// # Checks
+/// * Duplicate IDs among rooms, teachers, courses and groups.
+/// * Dangling references between courses, groups and teachers.
+/// * `Course::professor_id` vs `Teacher::course_id`, and `Course::group_ids` vs `Group::courses`.
+/// * Courses without groups.
+/// * Lab courses when no room is a laboratory.
+/// * Courses with more students than any room they could use.
+/// * An empty week grid, and courses needing more sessions than the week has slots.
+/// * Pins: unknown courses, rooms or sessions, days/slots outside the week,
+///   sessions pinned twice, and pinned sessions that clash with each other
+///   (skipped when the week is empty).
+/// * Travel times between buildings that no room is in.
+/// * Lunch slots (`weights.lunch_slots`) outside the week.
+///
+/// # Returns
+/// `Ok(())` if the input is consistent, otherwise the full list of problems.
+pub fn validate_input(input: &TimetableInput) -> Result<(), Vec<ValidationError>> {
+    let mut errors = Vec::new();
+
+    check_duplicates(&mut errors, "room", input.rooms.iter().map(|r| r.id));
+    check_duplicates(&mut errors, "teacher", input.teachers.iter().map(|t| t.id));
+    check_duplicates(&mut errors, "course", input.courses.iter().map(|c| c.id));
+    check_duplicates(&mut errors, "group", input.groups.iter().map(|g| g.id));
+
+    let teachers: HashMap<usize, _> = input.teachers.iter().map(|t| (t.id, t)).collect();
+    let courses: HashMap<usize, _> = input.courses.iter().map(|c| (c.id, c)).collect();
+    let groups: HashMap<usize, _> = input.groups.iter().map(|g| (g.id, g)).collect();
+
+    for course in &input.courses {
+        match teachers.get(&course.professor_id) {
+            None => errors.push(ValidationError::DanglingReference {
+                from: "Course", from_id: course.id, to: "teacher", to_id: course.professor_id,
+            }),
+            Some(teacher) if !teacher.course_id.contains(&course.id) => {
+                errors.push(ValidationError::ProfessorMismatch { course_id: course.id, teacher_id: teacher.id });
+            }
+            Some(_) => {}
+        }
+
+        if course.group_ids.is_empty() {
+            errors.push(ValidationError::CourseWithoutGroups { course_id: course.id });
+        }
+        for group_id in &course.group_ids {
+            match groups.get(group_id) {
+                None => errors.push(ValidationError::DanglingReference {
+                    from: "Course", from_id: course.id, to: "group", to_id: *group_id,
+                }),
+                Some(group) if !group.courses.contains(&course.id) => {
+                    errors.push(ValidationError::GroupMismatch { course_id: course.id, group_id: group.id });
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    for group in &input.groups {
+        for course_id in &group.courses {
+            match courses.get(course_id) {
+                None => errors.push(ValidationError::DanglingReference {
+                    from: "Group", from_id: group.id, to: "course", to_id: *course_id,
+                }),
+                Some(course) if !course.group_ids.contains(&group.id) => {
+                    errors.push(ValidationError::GroupMismatch { course_id: course.id, group_id: group.id });
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    for teacher in &input.teachers {
+        for course_id in &teacher.course_id {
+            match courses.get(course_id) {
+                None => errors.push(ValidationError::DanglingReference {
+                    from: "Teacher", from_id: teacher.id, to: "course", to_id: *course_id,
+                }),
+                Some(course) if course.professor_id != teacher.id => {
+                    errors.push(ValidationError::ProfessorMismatch { course_id: course.id, teacher_id: teacher.id });
+                }
+                Some(_) => {}
+            }
+        }
+    }
+
+    check_rooms(&mut errors, input, &groups);
+
+    if input.week.day_count() == 0 || input.week.slot_count() == 0 || input.week.slot_minutes == 0 {
+        errors.push(ValidationError::EmptyWeek);
+    } else {
+        for course in input.courses.iter().filter(|c| c.sessions_needed(&input.week) as usize > input.week.cell_count()) {
+            errors.push(ValidationError::TooManyHours { course_id: course.id, required_hours: course.required_hours });
+        }
+        // Pins are checked per session, which needs the slot length
+        check_pins(&mut errors, input, &courses);
+        for &slot in input.weights.lunch_slots.iter().filter(|&&slot| slot as usize >= input.week.slot_count()) {
+            errors.push(ValidationError::LunchSlotOutsideWeek { slot });
+        }
+    }
+    check_travel_times(&mut errors, input);
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+/// Checks the room domains of the normalized input: every course needs at least one room it can use.
+///
+/// # Arguments
+/// * `domains` - `TimetableInput::room_domains` of `input`.
+pub fn validate_room_domains(input: &TimetableInput, domains: &[Vec<usize>]) -> Result<(), Vec<ValidationError>> {
+    let errors: Vec<ValidationError> = domains.iter()
+        .enumerate()
+        .filter(|(_, rooms)| rooms.is_empty())
+        .map(|(course_id, _)| ValidationError::NoFeasibleRoom { course_id: input.get_course(course_id).id })
+        .collect();
+
+    if errors.is_empty() { Ok(()) } else { Err(errors) }
+}
+
+fn check_duplicates(errors: &mut Vec<ValidationError>, kind: &'static str, ids: impl Iterator<Item = usize>) {
+    let mut seen = HashSet::new();
+    let mut reported = HashSet::new();
+    for id in ids {
+        if !seen.insert(id) && reported.insert(id) {
+            errors.push(ValidationError::DuplicateId { kind, id });
+        }
+    }
+}
+
+/// Checks the pins on their own and against each other.
+///
+/// Two pinned sessions clash when both fix the same (Day, Slot) and they have
+/// the same fixed room, the same teacher or a group in common.
+fn check_pins(errors: &mut Vec<ValidationError>, input: &TimetableInput, courses: &HashMap<usize, &Course>) {
+    let rooms: HashSet<usize> = input.rooms.iter().map(|r| r.id).collect();
+    let mut pinned_sessions = HashSet::new();
+    // (Course, Day, Slot, RoomID) of every session with a fixed time.
+    let mut timed: Vec<(&Course, u32, u32, Option<usize>)> = Vec::new();
+
+    for pin in &input.pinned {
+        if let Some(room_id) = pin.room_id
+            && !rooms.contains(&room_id)
+        {
+            errors.push(ValidationError::DanglingReference { from: "Pin of course", from_id: pin.course_id, to: "room", to_id: room_id });
+        }
+        let Some(&course) = courses.get(&pin.course_id) else {
+            errors.push(ValidationError::DanglingReference { from: "Pin of course", from_id: pin.course_id, to: "course", to_id: pin.course_id });
+            continue;
+        };
+        if pin.day.is_some_and(|day| day as usize >= input.week.day_count())
+            || pin.slot.is_some_and(|slot| slot as usize >= input.week.slot_count())
+        {
+            errors.push(ValidationError::PinOutsideWeek { course_id: course.id });
+            continue;
+        }
+
+        let sessions_needed = course.sessions_needed(&input.week);
+        let indices = match pin.session_index {
+            Some(index) if index >= sessions_needed => {
+                errors.push(ValidationError::PinnedSessionMissing { course_id: course.id, session_index: index });
+                continue;
+            }
+            Some(index) => index..index + 1,
+            None => 0..sessions_needed,
+        };
+        for session_index in indices {
+            if !pinned_sessions.insert((course.id, session_index)) {
+                errors.push(ValidationError::DuplicatePin { course_id: course.id, session_index });
+                continue;
+            }
+            if let (Some(day), Some(slot)) = (pin.day, pin.slot) {
+                timed.push((course, day, slot, pin.room_id));
+            }
+        }
+    }
+
+    for (i, &(course, day, slot, room_id)) in timed.iter().enumerate() {
+        for &(other, other_day, other_slot, other_room_id) in &timed[i + 1..] {
+            if (day, slot) != (other_day, other_slot) {
+                continue;
+            }
+            let shared = if room_id.is_some() && room_id == other_room_id {
+                "room"
+            } else if course.professor_id == other.professor_id {
+                "teacher"
+            } else if course.group_ids.iter().any(|g| other.group_ids.contains(g)) {
+                "group"
+            } else {
+                continue;
+            };
+            errors.push(ValidationError::PinClash { course_id: course.id, other_course_id: other.id, day, slot, shared });
+        }
+    }
+}
+
+/// Checks that `travel_times` only mentions buildings that have rooms.
+fn check_travel_times(errors: &mut Vec<ValidationError>, input: &TimetableInput) {
+    let buildings: HashSet<usize> = input.rooms.iter().map(|r| r.building_id).collect();
+
+    for travel in &input.travel_times {
+        for building in [travel.from_building, travel.to_building] {
+            if !buildings.contains(&building) {
+                errors.push(ValidationError::DanglingReference {
+                    from: "Travel time from building",
+                    from_id: travel.from_building,
+                    to: "building",
+                    to_id: building,
+                });
+            }
+        }
+    }
+}
+
+/// Checks that every course has at least one room of the right type that is big enough.
+fn check_rooms(errors: &mut Vec<ValidationError>, input: &TimetableInput, groups: &HashMap<usize, &Group>) {
+    let has_lab = input.rooms.iter().any(|r| r.is_laboratory);
+
+    for course in &input.courses {
+        if course.required_lab && !has_lab {
+            errors.push(ValidationError::NoLabRoom { course_id: course.id });
+            continue;
+        }
+
+        // Dangling group IDs are reported above, here they just count as 0 students.
+        let needed: u32 = course.group_ids.iter()
+            .filter_map(|id| groups.get(id))
+            .map(|g| g.numbers_of_students)
+            .sum();
+        let largest = input.rooms.iter()
+            .filter(|r| r.is_laboratory || !course.required_lab)
+            .map(|r| r.capacity)
+            .max()
+            .unwrap_or(0);
+
+        if needed > largest {
+            errors.push(ValidationError::CapacityExceedsEveryRoom { course_id: course.id, needed, largest });
+        }
+    }
+}
@@ -1,3 +1,5 @@
+#![allow(non_snake_case)]
+
 pub mod domain;
 pub mod solver;
 pub mod io;
@@ -1,11 +1,28 @@
+#![allow(non_snake_case)]
+
 use UCTP::io::{read_input::read_json, normalize_input::normalize_data};
-use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+use UCTP::io::validate_input::{validate_input, validate_room_domains, ValidationError};
+use UCTP::solver::registry::{create_solver, DEFAULT_SOLVER};
+use UCTP::solver::stop::StopCondition;
 use UCTP::io::output::print_schedule;
+use UCTP::io::export::{read_previous_schedule, write_report_json, write_schedule_json};
+use UCTP::domain::schedule::Schedule;
 use UCTP::domain::config::Config;
 
 fn main() {
-    let config = Config::load().expect("Failed to load config.toml");
-    let raw_input = match read_json(&config.file_name) {
+    let mut config = match Config::load() {
+        Ok(config) => config,
+        Err(e) => {
+            eprintln!("Error, failed to load config.toml: {}", e);
+            std::process::exit(1);
+        }
+    };
+    if let Err(e) = config.apply_args(std::env::args().skip(1)) {
+        eprintln!("Error, {}", e);
+        eprintln!("Usage: UCTP [--algorithm <name>] [--initial <random|greedy>] [--warm-start <file>] [--seed <u64>] [--time-limit <seconds>] [--chains <n>]");
+        std::process::exit(1);
+    }
+    let mut raw_input = match read_json(&config.file_name) {
         Ok(data) => data,
         Err(e) => {
             eprintln!("Error, failed to load input: {}", e);
@@ -13,12 +30,89 @@ fn main() {
         }
     };
 
-    let normalized_input = normalize_data(raw_input);
+    // Settings from config.toml go in before validation, so they are checked too
+    if let Err(e) = config.apply_to(&mut raw_input) {
+        eprintln!("Error, failed to load config.toml: {}", e);
+        std::process::exit(1);
+    }
+    if let Err(errors) = validate_input(&raw_input) {
+        exit_with_problems(&errors);
+    }
+
+    let mut normalized_input = normalize_data(raw_input);
+    if let Err(errors) = validate_room_domains(&normalized_input, &normalized_input.room_domains()) {
+        exit_with_problems(&errors);
+    }
+
+    if let Some(previous_file) = &config.previous_schedule {
+        let weight = config.perturbation_weight.unwrap_or(10);
+        match read_previous_schedule(previous_file, &normalized_input, weight) {
+            Ok(previous) => {
+                let total = Schedule::sessions_for(&normalized_input).len();
+                println!("Warm start from {}: {} of {} sessions placed before", previous_file, previous.known_sessions(), total);
+                normalized_input.previous = Some(previous);
+            }
+            Err(e) => {
+                eprintln!("Error, failed to load the previous schedule: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    let seed = config.seed.unwrap_or_else(rand::random);
+    println!("Seed: {}", seed);
+
+    let algorithm = config.algorithm.as_deref().unwrap_or(DEFAULT_SOLVER);
+    let stop = StopCondition::from_config(&config);
+    let solver = match create_solver(algorithm, normalized_input.clone(), stop, seed, &config) {
+        Ok(solver) => solver,
+        Err(e) => {
+            eprintln!("Error, {}", e);
+            std::process::exit(1);
+        }
+    };
+    println!("Algorithm: {} (initial solution: {})", solver.name(), config.initial_solution);
 
-    let sa = SimulatedAnnealing::new(normalized_input.clone() , config.start_temp, config.cooling_rate, config.max_iterations);
-    let schedule = sa.run();
-    let best_penalty = schedule.calculate_penalty(&normalized_input);
+    let outcome = solver.solve();
+    println!("Stopped after {} iterations in {:.2?}: {}", outcome.iterations, outcome.elapsed, outcome.stop_reason);
+    if !outcome.move_stats.is_empty() {
+        print!("{}", outcome.move_stats);
+    }
+    let schedule = outcome.schedule;
+    let report = schedule.penalty_report(&normalized_input);
 
     print_schedule(&schedule, &normalized_input);
-    println!("The penalty is {}", best_penalty);
+    println!("The penalty is {}", report.total);
+    if !report.total.is_zero() {
+        print!("{}", report);
+    }
+
+    if let Some(report_file) = &config.report_file {
+        match write_report_json(report_file, &report) {
+            Ok(()) => println!("Penalty report written to {}", report_file),
+            Err(e) => {
+                eprintln!("Error, failed to write the penalty report: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+
+    if let Some(output_file) = &config.output_file {
+        match write_schedule_json(output_file, &schedule, &normalized_input) {
+            Ok(()) => println!("Schedule written to {}", output_file),
+            Err(e) => {
+                eprintln!("Error, failed to write the schedule: {}", e);
+                std::process::exit(1);
+            }
+        }
+    }
+}
+
+/// Prints every problem of the input and exits with code 2.
+fn exit_with_problems(errors: &[ValidationError]) -> ! {
+    eprintln!("Error, the input has {} problem(s):", errors.len());
+    for error in errors {
+        eprintln!("  - {}", error);
+    }
+    std::process::exit(2);
 }
@@ -0,0 +1,139 @@
+use crate::domain::{input_wrapper::TimetableInput, schedule::{Schedule, Session}};
+use crate::solver::evaluator::IncrementalEvaluator;
+use crate::solver::moves::MoveGenerator;
+use rand::Rng;
+use serde::Deserialize;
+use std::cmp::Reverse;
+use std::fmt;
+use std::str::FromStr;
+
+/// How a solver builds the schedule it starts from.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum InitialSolution {
+    /// Every session at a random open (Day, Slot, RoomID).
+    #[default]
+    Random,
+    /// [`greedy_schedule`]: the hardest courses first, each at its cheapest assignment.
+    Greedy,
+}
+
+impl InitialSolution {
+    /// Builds the starting schedule.
+    ///
+    /// With `input.previous` set this is always a warm start: sessions keep
+    /// their previous assignment and only new sessions are placed, greedily.
+    pub fn build(&self, input: &TimetableInput, moves: &MoveGenerator, rng: &mut impl Rng) -> Schedule {
+        if let Some(previous) = &input.previous {
+            return complete_schedule(input, |session| previous.get(session));
+        }
+        match self {
+            InitialSolution::Random => moves.random_schedule(input, rng),
+            InitialSolution::Greedy => greedy_schedule(input),
+        }
+    }
+}
+
+impl FromStr for InitialSolution {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "random" => Ok(InitialSolution::Random),
+            "greedy" => Ok(InitialSolution::Greedy),
+            _ => Err(format!("unknown initial solution '{}', expected random or greedy", name)),
+        }
+    }
+}
+
+impl fmt::Display for InitialSolution {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InitialSolution::Random => write!(f, "random"),
+            InitialSolution::Greedy => write!(f, "greedy"),
+        }
+    }
+}
+
+/// Builds a schedule one session at a time, in the spirit of DSatur graph colouring.
+///
+/// Courses are ordered by how hard they are to place: fewest rooms that fit
+/// them (size and lab need) first, then the most groups, then the most students.
+///
+/// Every session is then put at the open (Day, Slot, RoomID) of its room
+/// domain that adds the least penalty to the sessions already placed.
+/// The result is deterministic.
+pub fn greedy_schedule(input: &TimetableInput) -> Schedule {
+    complete_schedule(input, |_| None)
+}
+
+/// Greedy construction around sessions whose assignment is already decided.
+///
+/// Sessions pinned to a day, slot and room, and those for which `fixed` returns
+/// an assignment their pin allows, are placed first; the others are placed like
+/// in [`greedy_schedule`], partially pinned ones first and within their pin.
+pub fn complete_schedule(input: &TimetableInput, fixed: impl Fn(Session) -> Option<(u32, u32, usize)>) -> Schedule {
+    let sessions = Schedule::sessions_for(input);
+    let fixed = |session: Session| match input.pin(session) {
+        Some(pin) => pin.fixed().or_else(|| fixed(session).filter(|&assignment| pin.allows(assignment))),
+        None => fixed(session),
+    };
+
+    let domains = input.room_domains();
+    let difficulty = |course_id: usize| {
+        let course = input.get_course(course_id);
+        (domains[course_id].len(), Reverse(course.group_ids.len()), Reverse(course.capacity_needed(&input.groups)))
+    };
+    let mut order: Vec<usize> = (0..sessions.len()).filter(|&session_id| fixed(sessions[session_id]).is_none()).collect();
+    order.sort_by_key(|&session_id| (input.pin(sessions[session_id]).is_none(), difficulty(sessions[session_id].course_id)));
+
+    // Every open (Day, Slot, RoomID), or every cell if all rooms are blocked all week.
+    let open_cells: Vec<Vec<(u32, u32)>> = (0..input.rooms.len()).map(|room_id| input.open_cells(room_id)).collect();
+    let mut all_options: Vec<(u32, u32, usize)> = open_cells.iter()
+        .enumerate()
+        .flat_map(|(room_id, cells)| cells.iter().map(move |&(day, slot)| (day, slot, room_id)))
+        .collect();
+    if all_options.is_empty() {
+        all_options = (0..input.rooms.len())
+            .flat_map(|room_id| (0..input.week.cell_count()).map(move |cell| (cell, room_id)))
+            .map(|(cell, room_id)| ((cell / input.week.slot_count()) as u32, (cell % input.week.slot_count()) as u32, room_id))
+            .collect();
+    }
+    let course_options: Vec<Vec<(u32, u32, usize)>> = domains.iter()
+        .map(|domain| {
+            let options: Vec<_> = domain.iter()
+                .flat_map(|&room_id| open_cells[room_id].iter().map(move |&(day, slot)| (day, slot, room_id)))
+                .collect();
+            if options.is_empty() { all_options.clone() } else { options }
+        })
+        .collect();
+
+    let mut evaluator = IncrementalEvaluator::new(input, Schedule { sessions: vec![], assignments: vec![] });
+    let mut assignments = vec![(0, 0, 0); sessions.len()];
+    for (session_id, &session) in sessions.iter().enumerate() {
+        if let Some(assignment) = fixed(session) {
+            evaluator.add_session(session, assignment);
+            assignments[session_id] = assignment;
+        }
+    }
+    for session_id in order {
+        let placed_id = evaluator.schedule().sessions.len();
+        let course_id = sessions[session_id].course_id;
+        let pinned_options = input.pin(sessions[session_id]).map(|pin| input.pinned_options(pin, &domains[course_id]));
+        let options = match &pinned_options {
+            Some(options) if !options.is_empty() => options,
+            _ => &course_options[course_id],
+        };
+        let mut best = (evaluator.add_session(sessions[session_id], options[0]), options[0]);
+        for &option in &options[1..] {
+            let penalty = evaluator.apply_move(placed_id, option);
+            if penalty < best.0 {
+                best = (penalty, option);
+            }
+        }
+        evaluator.apply_move(placed_id, best.1);
+        assignments[session_id] = best.1;
+    }
+
+    Schedule { sessions, assignments }
+}
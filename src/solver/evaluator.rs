@@ -0,0 +1,303 @@
+use crate::domain::input_wrapper::TimetableInput;
+use crate::domain::report::{AttendeeId, ConstraintKind};
+use crate::domain::score::Score;
+use crate::domain::schedule::{
+    day_penalty, perturbation_penalty, room_penalty, teacher_day_load, teacher_slot_penalty, teacher_week_load,
+    Schedule, Session,
+};
+
+/// Keeps a `Schedule` together with the occupancy grids needed to score it,
+/// so that moving one session only re-scores what the move touched.
+///
+/// The grids mirror what `Schedule::calculate_penalty` rebuilds from scratch:
+/// * one `[Day][Slot][RoomID]` counter for room double booking,
+/// * one `[Day][Slot]` cell per Group and per Teacher holding the sessions placed there.
+///
+/// It also indexes the sessions of every `[Day][Slot]`, which the compound moves need.
+///
+/// The penalty of every (entity, day) pair is cached, so a move only
+/// recomputes the days it leaves and enters for the groups and the teachers
+/// of the moved session's course. The free days of those teachers are
+/// counted again, as they depend on the whole week.
+#[derive(Debug, Clone)]
+pub struct IncrementalEvaluator<'a> {
+    input: &'a TimetableInput,
+    schedule: Schedule,
+    /// Course ID -> entities (groups first, then teachers) attending it.
+    course_entities: Vec<Vec<usize>>,
+    /// Entity -> [Day * slot_count + Slot] -> sessions placed in that cell.
+    entity_cells: Vec<Vec<Vec<usize>>>,
+    /// Entity -> [Day] -> cached penalty of that day.
+    entity_day_penalty: Vec<Vec<Score>>,
+    /// Teacher ID -> cached penalty of its whole week (free days).
+    teacher_week_penalty: Vec<Score>,
+    /// [Day][Slot][RoomID] flattened -> number of sessions using the room.
+    room_usage: Vec<u32>,
+    /// [Day * slot_count + Slot] -> sessions placed in that cell.
+    cell_sessions: Vec<Vec<usize>>,
+    penalty: Score,
+}
+
+impl<'a> IncrementalEvaluator<'a> {
+    /// Builds all the grids for `schedule` and computes its full penalty once.
+    pub fn new(input: &'a TimetableInput, schedule: Schedule) -> Self {
+        let entity_count = input.groups.len() + input.teachers.len();
+        let mut course_entities = vec![Vec::new(); input.courses.len()];
+
+        let group_courses = input.groups.iter().map(|g| &g.courses);
+        let teacher_courses = input.teachers.iter().map(|t| &t.course_id);
+        for (entity, courses) in group_courses.chain(teacher_courses).enumerate() {
+            for &course_id in courses {
+                course_entities[course_id].push(entity);
+            }
+        }
+
+        let mut evaluator = Self {
+            input,
+            schedule,
+            course_entities,
+            entity_cells: vec![vec![Vec::new(); input.week.cell_count()]; entity_count],
+            entity_day_penalty: vec![vec![Score::ZERO; input.week.day_count()]; entity_count],
+            teacher_week_penalty: vec![Score::ZERO; input.teachers.len()],
+            room_usage: vec![0; input.week.cell_count() * input.rooms.len()],
+            cell_sessions: vec![Vec::new(); input.week.cell_count()],
+            penalty: Score::ZERO,
+        };
+
+        for session_id in 0..evaluator.schedule.assignments.len() {
+            let assignment = evaluator.schedule.assignments[session_id];
+            let session = evaluator.schedule.sessions[session_id];
+            let course_id = session.course_id;
+            let placed = evaluator.place(session, assignment);
+            evaluator.penalty += placed;
+            let cell = evaluator.cell(assignment);
+            evaluator.cell_sessions[cell].push(session_id);
+            for i in 0..evaluator.course_entities[course_id].len() {
+                let entity = evaluator.course_entities[course_id][i];
+                let cell = evaluator.cell(assignment);
+                evaluator.entity_cells[entity][cell].push(session_id);
+            }
+        }
+        for entity in 0..entity_count {
+            for day in 0..input.week.day_count() {
+                let day_penalty = evaluator.score_day(entity, day);
+                evaluator.entity_day_penalty[entity][day] = day_penalty;
+                evaluator.penalty += day_penalty;
+            }
+            evaluator.rescore_week(entity);
+        }
+        evaluator
+    }
+
+    /// The total penalty of the current schedule.
+    pub fn penalty(&self) -> Score {
+        self.penalty
+    }
+
+    /// The schedule in its current state.
+    pub fn schedule(&self) -> &Schedule {
+        &self.schedule
+    }
+
+    /// Consumes the evaluator and hands back the schedule.
+    pub fn into_schedule(self) -> Schedule {
+        self.schedule
+    }
+
+    /// The input the schedule is scored against.
+    pub fn input(&self) -> &'a TimetableInput {
+        self.input
+    }
+
+    /// Sessions currently placed at (`day`, `slot`), in any room.
+    pub fn sessions_at(&self, day: u32, slot: u32) -> &[usize] {
+        &self.cell_sessions[self.cell((day, slot, 0))]
+    }
+
+    /// Returns true if no session uses `room_id` at (`day`, `slot`).
+    pub fn is_room_free(&self, day: u32, slot: u32, room_id: usize) -> bool {
+        self.room_usage[self.cell((day, slot, 0)) * self.input.rooms.len() + room_id] == 0
+    }
+
+    /// Groups (by index) and Teachers (by index offset by the group count) attending `course_id`.
+    pub(crate) fn course_entities(&self, course_id: usize) -> &[usize] {
+        &self.course_entities[course_id]
+    }
+
+    /// Applies several moves one after the other and returns the new total penalty.
+    pub fn apply_moves(&mut self, moves: &[(usize, (u32, u32, usize))]) -> Score {
+        for &(session_id, assignment) in moves {
+            self.apply_move(session_id, assignment);
+        }
+        self.penalty
+    }
+
+    /// Moves `session_id` to `assignment` (Day, Slot, RoomID) and returns the new total penalty.
+    ///
+    /// To undo a move, apply the previous assignment of the session again.
+    pub fn apply_move(&mut self, session_id: usize, assignment: (u32, u32, usize)) -> Score {
+        let previous = self.schedule.assignments[session_id];
+        if previous == assignment {
+            return self.penalty;
+        }
+        let session = self.schedule.sessions[session_id];
+        let course_id = session.course_id;
+
+        // Room part: fit of the new room, double booking of both cells and distance from the previous schedule.
+        let removed = self.unplace(session, previous);
+        let added = self.place(session, assignment);
+        self.penalty = self.penalty - removed + added;
+        self.schedule.assignments[session_id] = assignment;
+
+        // Entity part: move the session between cells, then re-score the touched days.
+        let (old_cell, new_cell) = (self.cell(previous), self.cell(assignment));
+        if old_cell != new_cell {
+            let sessions = &mut self.cell_sessions[old_cell];
+            let position = sessions.iter().position(|&s| s == session_id).expect("session missing from its cell");
+            sessions.swap_remove(position);
+            self.cell_sessions[new_cell].push(session_id);
+        }
+        let mut entities = self.course_entities[course_id].clone();
+        for &entity in &entities {
+            let cells = &mut self.entity_cells[entity];
+            let position = cells[old_cell].iter().position(|&s| s == session_id).expect("session missing from its cell");
+            cells[old_cell].swap_remove(position);
+            cells[new_cell].push(session_id);
+        }
+        entities.sort_unstable();
+        entities.dedup();
+
+        for entity in entities {
+            self.rescore_day(entity, previous.0 as usize);
+            if assignment.0 != previous.0 {
+                self.rescore_day(entity, assignment.0 as usize);
+                self.rescore_week(entity);
+            }
+        }
+        self.penalty
+    }
+
+    /// Appends `session` to the schedule at `assignment` and returns the new total penalty.
+    ///
+    /// Lets a schedule be built one session at a time; the new session gets the next session ID.
+    pub fn add_session(&mut self, session: Session, assignment: (u32, u32, usize)) -> Score {
+        let session_id = self.schedule.sessions.len();
+        let course_id = session.course_id;
+        self.schedule.sessions.push(session);
+        self.schedule.assignments.push(assignment);
+        let placed = self.place(session, assignment);
+        self.penalty += placed;
+
+        let cell = self.cell(assignment);
+        self.cell_sessions[cell].push(session_id);
+        let mut entities = self.course_entities[course_id].clone();
+        for &entity in &entities {
+            self.entity_cells[entity][cell].push(session_id);
+        }
+        entities.sort_unstable();
+        entities.dedup();
+
+        for entity in entities {
+            self.rescore_day(entity, assignment.0 as usize);
+            self.rescore_week(entity);
+        }
+        self.penalty
+    }
+
+    /// Adds `session` to the room grid and returns the penalty it brings.
+    fn place(&mut self, session: Session, assignment: (u32, u32, usize)) -> Score {
+        let (_, _, room_id) = assignment;
+        let cell = self.cell(assignment);
+        let usage = &mut self.room_usage[cell * self.input.rooms.len() + room_id];
+        let double_booked = *usage > 0;
+        *usage += 1;
+        let double_booking = self.points(ConstraintKind::RoomDoubleBooking, usize::from(double_booked));
+
+        let mut penalty = double_booking;
+        room_penalty(self.input, session.course_id, assignment, &mut penalty);
+        perturbation_penalty(self.input, session, assignment, &mut penalty);
+        penalty
+    }
+
+    /// Removes `session` from the room grid and returns the penalty it took away.
+    fn unplace(&mut self, session: Session, assignment: (u32, u32, usize)) -> Score {
+        let (_, _, room_id) = assignment;
+        let cell = self.cell(assignment);
+        let usage = &mut self.room_usage[cell * self.input.rooms.len() + room_id];
+        *usage -= 1;
+        let double_booked = *usage > 0;
+        let double_booking = self.points(ConstraintKind::RoomDoubleBooking, usize::from(double_booked));
+
+        let mut penalty = double_booking;
+        room_penalty(self.input, session.course_id, assignment, &mut penalty);
+        perturbation_penalty(self.input, session, assignment, &mut penalty);
+        penalty
+    }
+
+    fn rescore_day(&mut self, entity: usize, day: usize) {
+        let day_penalty = self.score_day(entity, day);
+        self.penalty -= self.entity_day_penalty[entity][day];
+        self.penalty += day_penalty;
+        self.entity_day_penalty[entity][day] = day_penalty;
+    }
+
+    /// Re-scores the free days of `entity` if it is a teacher.
+    fn rescore_week(&mut self, entity: usize) {
+        let Some(teacher_id) = entity.checked_sub(self.input.groups.len()) else {
+            return;
+        };
+        let slot_count = self.input.week.slot_count();
+        let busy_days = self.entity_cells[entity]
+            .chunks(slot_count)
+            .filter(|day| day.iter().any(|sessions| !sessions.is_empty()))
+            .count();
+        let mut week_penalty = Score::ZERO;
+        teacher_week_load(self.input, teacher_id, busy_days, &mut week_penalty);
+
+        self.penalty -= self.teacher_week_penalty[teacher_id];
+        self.penalty += week_penalty;
+        self.teacher_week_penalty[teacher_id] = week_penalty;
+    }
+
+    /// Same scoring as `Schedule::gap_teleportation_check` for a single day:
+    /// every extra session in a cell is a collision, and the session with the
+    /// lowest ID decides which room the entity is in.
+    /// For teachers, every session also pays `Schedule::teacher_availability_check`,
+    /// and the day its part of `Schedule::teacher_load_check`.
+    fn score_day(&self, entity: usize, day: usize) -> Score {
+        let slot_count = self.input.week.slot_count();
+        let (attendee, collision) = match entity.checked_sub(self.input.groups.len()) {
+            Some(teacher_id) => (AttendeeId::Teacher(teacher_id), ConstraintKind::TeacherCollision),
+            None => (AttendeeId::Group(entity), ConstraintKind::GroupCollision),
+        };
+        let mut penalty = Score::ZERO;
+        let mut day_sessions = vec![None::<usize>; slot_count];
+
+        for (slot, cell) in day_sessions.iter_mut().enumerate() {
+            let sessions = &self.entity_cells[entity][day * slot_count + slot];
+            *cell = sessions.iter().min().copied();
+            penalty += self.points(collision, sessions.len().saturating_sub(1));
+
+            if let AttendeeId::Teacher(teacher_id) = attendee {
+                for &session_id in sessions {
+                    let course_id = self.schedule.sessions[session_id].course_id;
+                    teacher_slot_penalty(self.input, teacher_id, course_id, day as u32, slot as u32, &mut penalty);
+                }
+            }
+        }
+        day_penalty(self.input, &self.schedule, attendee, day as u32, &day_sessions, &mut penalty);
+        if let AttendeeId::Teacher(teacher_id) = attendee {
+            teacher_day_load(self.input, teacher_id, day as u32, &day_sessions, &mut penalty);
+        }
+        penalty
+    }
+
+    /// Penalty of `count` violations of `kind`, weighted by `input.weights`.
+    fn points(&self, kind: ConstraintKind, count: usize) -> Score {
+        Score::of(kind.level(), count as u64 * u64::from(self.input.weights.points(kind)))
+    }
+
+    fn cell(&self, assignment: (u32, u32, usize)) -> usize {
+        assignment.0 as usize * self.input.week.slot_count() + assignment.1 as usize
+    }
+}
@@ -0,0 +1,165 @@
+use crate::domain::{input_wrapper::TimetableInput, schedule::Schedule, score::Score};
+use crate::solver::Solver;
+use crate::solver::construct::InitialSolution;
+use crate::solver::evaluator::IncrementalEvaluator;
+use crate::solver::moves::{MoveGenerator, MoveStats};
+use crate::solver::stop::{RunOutcome, StopCondition};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::Instant;
+
+type Assignments = Vec<(u32, u32, usize)>;
+
+/// Genetic algorithm on `Schedule.assignments`, optionally memetic.
+///
+/// Each generation (one iteration of the stop condition) keeps the best
+/// schedule and breeds the rest of the population:
+/// 1. Two parents are picked by tournament selection.
+/// 2. Crossover takes all the sessions of each course from one parent or the other.
+/// 3. Mutation gives each session a random new assignment with probability `mutation_rate`.
+/// 4. With `local_search_steps > 0`, the child is polished by that many
+///    hill-climbing moves (the same moves simulated annealing uses).
+#[derive(Debug, Clone)]
+pub struct GeneticAlgorithm {
+    pub input: TimetableInput,
+    pub population_size: usize,
+    pub tournament_size: usize,
+    /// Probability that a session of a child is moved at random.
+    pub mutation_rate: f64,
+    /// Hill-climbing moves per child; 0 turns the memetic polish off.
+    pub local_search_steps: u32,
+    pub stop: StopCondition,
+    pub seed: u64,
+    /// How the first individual is built; the rest of the first population is random.
+    pub initial: InitialSolution,
+    moves: MoveGenerator,
+}
+
+impl GeneticAlgorithm {
+    pub fn new(input: TimetableInput, population_size: usize, stop: StopCondition, seed: u64) -> Self {
+        let moves = MoveGenerator::new(&input);
+        Self {
+            input,
+            population_size,
+            tournament_size: 3,
+            mutation_rate: 0.02,
+            local_search_steps: 0,
+            stop,
+            seed,
+            initial: InitialSolution::Random,
+            moves,
+        }
+    }
+
+    /// Evolves the population until `self.stop` is met and reports why it stopped.
+    pub fn solve(&self) -> RunOutcome {
+        let started = Instant::now();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let sessions = Schedule::sessions_for(&self.input);
+        let course_sessions = Schedule { sessions: sessions.clone(), assignments: vec![] }
+            .sessions_by_course(self.input.courses.len());
+
+        let mut population: Vec<(Assignments, Score)> = (0..self.population_size.max(2))
+            .map(|individual| {
+                let schedule = match individual {
+                    0 => self.initial.build(&self.input, &self.moves, &mut rng),
+                    _ => self.moves.random_schedule(&self.input, &mut rng),
+                };
+                self.polish(schedule, &mut rng)
+            })
+            .collect();
+        let mut best = self.fittest(&population).clone();
+
+        let mut generation: u64 = 0;
+        let mut last_improvement: u64 = 0;
+        let stop_reason = loop {
+            if let Some(reason) = self.stop.check(generation, started, generation - last_improvement, best.1, 1) {
+                break reason;
+            }
+            generation += 1;
+
+            let mut next = Vec::with_capacity(population.len());
+            next.push(best.clone());
+            while next.len() < population.len() {
+                let first = &self.tournament(&population, &mut rng).0;
+                let second = &self.tournament(&population, &mut rng).0;
+
+                let mut child = first.clone();
+                for course in &course_sessions {
+                    if rng.gen_bool(0.5) {
+                        for &session_id in course {
+                            child[session_id] = second[session_id];
+                        }
+                    }
+                }
+                for (session_id, (session, assignment)) in sessions.iter().zip(child.iter_mut()).enumerate() {
+                    if rng.r#gen::<f64>() < self.mutation_rate {
+                        *assignment = self.moves.random_session_assignment(session_id, session.course_id, &mut rng);
+                    }
+                }
+
+                let schedule = Schedule { sessions: sessions.clone(), assignments: child };
+                next.push(self.polish(schedule, &mut rng));
+            }
+            population = next;
+
+            let fittest = self.fittest(&population);
+            if fittest.1 < best.1 {
+                best = fittest.clone();
+                last_improvement = generation;
+            }
+        };
+
+        RunOutcome {
+            schedule: Schedule { sessions, assignments: best.0 },
+            penalty: best.1,
+            iterations: generation,
+            elapsed: started.elapsed(),
+            stop_reason,
+            move_stats: MoveStats::default(),
+        }
+    }
+
+    /// Scores `schedule`, running the local search first when it is enabled.
+    fn polish(&self, schedule: Schedule, rng: &mut StdRng) -> (Assignments, Score) {
+        let mut evaluator = IncrementalEvaluator::new(&self.input, schedule);
+        let mut penalty = evaluator.penalty();
+
+        for _ in 0..self.local_search_steps {
+            if penalty.is_zero() {
+                break;
+            }
+            let (session_id, assignment) = self.moves.random_move(evaluator.schedule(), rng);
+            let previous = evaluator.schedule().assignments[session_id];
+            let neighbour_penalty = evaluator.apply_move(session_id, assignment);
+            if neighbour_penalty <= penalty {
+                penalty = neighbour_penalty;
+            } else {
+                evaluator.apply_move(session_id, previous);
+            }
+        }
+        (evaluator.into_schedule().assignments, penalty)
+    }
+
+    /// The best of `tournament_size` individuals drawn at random.
+    fn tournament<'p>(&self, population: &'p [(Assignments, Score)], rng: &mut StdRng) -> &'p (Assignments, Score) {
+        (0..self.tournament_size.max(1))
+            .map(|_| &population[rng.gen_range(0..population.len())])
+            .min_by_key(|individual| individual.1)
+            .expect("tournaments have at least one contestant")
+    }
+
+    fn fittest<'p>(&self, population: &'p [(Assignments, Score)]) -> &'p (Assignments, Score) {
+        population.iter().min_by_key(|individual| individual.1).expect("population is never empty")
+    }
+}
+
+impl Solver for GeneticAlgorithm {
+    fn name(&self) -> &'static str {
+        "genetic"
+    }
+
+    fn solve(&self) -> RunOutcome {
+        GeneticAlgorithm::solve(self)
+    }
+}
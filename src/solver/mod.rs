@@ -1 +1,23 @@
 pub mod simulated_annealing;
+pub mod evaluator;
+pub mod stop;
+pub mod multi_start;
+pub mod registry;
+pub mod moves;
+pub mod tabu_search;
+pub mod genetic;
+pub mod construct;
+
+use crate::solver::stop::RunOutcome;
+
+/// A metaheuristic that turns its input into a schedule.
+///
+/// Solvers are built by [`registry::create_solver`] with the input, the
+/// stop condition and the seed, so running one needs nothing else.
+pub trait Solver {
+    /// The name the solver is registered under.
+    fn name(&self) -> &'static str;
+
+    /// Runs until the stop condition is met and returns the best schedule with its stats.
+    fn solve(&self) -> RunOutcome;
+}
@@ -0,0 +1,373 @@
+use crate::domain::{input_wrapper::TimetableInput, pin::Pin, schedule::Schedule};
+use crate::solver::evaluator::IncrementalEvaluator;
+use rand::Rng;
+use rand::seq::SliceRandom;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+type Assignment = (u32, u32, usize);
+
+/// The kinds of neighbourhood moves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+pub enum MoveKind {
+    /// One session to a random (Day, Slot, RoomID) of its domain.
+    Reassign,
+    /// Two sessions exchange their (Day, Slot), each keeping its room.
+    SwapTimes,
+    /// Two sessions in the same (Day, Slot) exchange rooms.
+    SwapRooms,
+    /// One session to a free room of its domain, in the same (Day, Slot).
+    FreeRoom,
+    /// Two (Day, Slot) cells exchange a Kempe chain: the sessions connected
+    /// through shared groups or teachers all change cell, so no new clash appears between them.
+    KempeChain,
+}
+
+impl MoveKind {
+    pub const ALL: [MoveKind; 5] =
+        [MoveKind::Reassign, MoveKind::SwapTimes, MoveKind::SwapRooms, MoveKind::FreeRoom, MoveKind::KempeChain];
+}
+
+/// How often each move kind is picked. Weights are relative and do not need to sum to 1.
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+#[serde(default)]
+pub struct MoveMix {
+    pub reassign: f64,
+    pub swap_times: f64,
+    pub swap_rooms: f64,
+    pub free_room: f64,
+    pub kempe_chain: f64,
+}
+
+impl Default for MoveMix {
+    fn default() -> Self {
+        Self { reassign: 0.5, swap_times: 0.2, swap_rooms: 0.1, free_room: 0.1, kempe_chain: 0.1 }
+    }
+}
+
+impl MoveMix {
+    /// Only the single-session re-assignment.
+    pub fn reassign_only() -> Self {
+        Self { reassign: 1.0, swap_times: 0.0, swap_rooms: 0.0, free_room: 0.0, kempe_chain: 0.0 }
+    }
+
+    fn weight(&self, kind: MoveKind) -> f64 {
+        let weight = match kind {
+            MoveKind::Reassign => self.reassign,
+            MoveKind::SwapTimes => self.swap_times,
+            MoveKind::SwapRooms => self.swap_rooms,
+            MoveKind::FreeRoom => self.free_room,
+            MoveKind::KempeChain => self.kempe_chain,
+        };
+        if weight.is_finite() { weight.max(0.0) } else { 0.0 }
+    }
+
+    /// Draws a move kind; re-assignment if every weight is 0.
+    fn pick(&self, rng: &mut impl Rng) -> MoveKind {
+        let total: f64 = MoveKind::ALL.iter().map(|&kind| self.weight(kind)).sum();
+        if total <= 0.0 {
+            return MoveKind::Reassign;
+        }
+        let mut target = rng.r#gen::<f64>() * total;
+        for kind in MoveKind::ALL {
+            target -= self.weight(kind);
+            if target < 0.0 {
+                return kind;
+            }
+        }
+        MoveKind::Reassign
+    }
+}
+
+/// A neighbourhood move: new assignments for one or more sessions, applied in order.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Move {
+    pub kind: MoveKind,
+    pub changes: Vec<(usize, Assignment)>,
+}
+
+impl Move {
+    /// The changes that put `schedule` back as it is now, once this move has been applied to it.
+    pub fn undo(&self, schedule: &Schedule) -> Vec<(usize, Assignment)> {
+        self.changes.iter().rev().map(|&(session_id, _)| (session_id, schedule.assignments[session_id])).collect()
+    }
+}
+
+/// Proposed and accepted moves per kind.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MoveStats {
+    proposed: [u64; MoveKind::ALL.len()],
+    accepted: [u64; MoveKind::ALL.len()],
+}
+
+impl MoveStats {
+    pub fn record(&mut self, kind: MoveKind, accepted: bool) {
+        self.proposed[kind as usize] += 1;
+        if accepted {
+            self.accepted[kind as usize] += 1;
+        }
+    }
+
+    /// Counts an accepted move that was already recorded as proposed.
+    pub fn record_accepted(&mut self, kind: MoveKind) {
+        self.accepted[kind as usize] += 1;
+    }
+
+    pub fn proposed(&self, kind: MoveKind) -> u64 {
+        self.proposed[kind as usize]
+    }
+
+    pub fn accepted(&self, kind: MoveKind) -> u64 {
+        self.accepted[kind as usize]
+    }
+
+    /// Adds the counts of `other`, e.g. of another chain.
+    pub fn merge(&mut self, other: &MoveStats) {
+        for kind in MoveKind::ALL {
+            self.proposed[kind as usize] += other.proposed(kind);
+            self.accepted[kind as usize] += other.accepted(kind);
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.proposed.iter().all(|&count| count == 0)
+    }
+}
+
+impl fmt::Display for MoveStats {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Moves (accepted / proposed):")?;
+        for kind in MoveKind::ALL {
+            let (accepted, proposed) = (self.accepted(kind), self.proposed(kind));
+            if proposed == 0 {
+                continue;
+            }
+            let rate = 100.0 * accepted as f64 / proposed as f64;
+            writeln!(f, "  {:<12} {:>10} / {:<10} ({:.1}%)", format!("{:?}", kind), accepted, proposed, rate)?;
+        }
+        Ok(())
+    }
+}
+
+/// Random moves shared by every solver.
+///
+/// Knows which (Day, Slot) cells each room is open in and which rooms each
+/// course can use (`TimetableInput::room_domains`), so moves never put a
+/// session in a room that is blocked, too small or missing a lab.
+///
+/// Pinned sessions (`TimetableInput::pinned`) only ever get assignments their
+/// pin allows, and sessions with day, slot and room all fixed are never moved.
+#[derive(Debug, Clone)]
+pub struct MoveGenerator {
+    day_count: u32,
+    slot_count: u32,
+    room_count: usize,
+    /// RoomID -> (Day, Slot) cells in which the room is not blocked.
+    open_cells: Vec<Vec<(u32, u32)>>,
+    /// CourseID -> rooms the course is sampled from.
+    ///
+    /// Its room domain, or every room with an open cell if the domain is empty.
+    course_rooms: Vec<Vec<usize>>,
+    /// SessionID -> its pin and the assignments it allows (`None` if not pinned).
+    pins: Vec<Option<(Pin, Vec<Assignment>)>>,
+    /// Sessions that have more than one possible assignment.
+    movable: Vec<usize>,
+}
+
+impl MoveGenerator {
+    pub fn new(input: &TimetableInput) -> Self {
+        let open_cells: Vec<Vec<(u32, u32)>> = (0..input.rooms.len()).map(|room_id| input.open_cells(room_id)).collect();
+        let usable_rooms: Vec<usize> = (0..input.rooms.len()).filter(|&room_id| !open_cells[room_id].is_empty()).collect();
+        let course_rooms: Vec<Vec<usize>> = input.room_domains()
+            .into_iter()
+            .map(|domain| if domain.is_empty() { usable_rooms.clone() } else { domain })
+            .collect();
+        let pins: Vec<_> = Schedule::sessions_for(input).into_iter()
+            .map(|session| {
+                let pin = input.pin(session)?;
+                Some((pin.clone(), input.pinned_options(pin, &course_rooms[session.course_id])))
+            })
+            .collect();
+        let movable = (0..pins.len())
+            .filter(|&session_id| pins[session_id].as_ref().is_none_or(|(_, options)| options.len() != 1))
+            .collect();
+        Self {
+            day_count: input.week.day_count() as u32,
+            slot_count: input.week.slot_count() as u32,
+            room_count: input.rooms.len(),
+            open_cells,
+            course_rooms,
+            pins,
+            movable,
+        }
+    }
+
+    /// Picks a random (Day, Slot, RoomID) for a session of `course_id`: a room
+    /// of its domain, in a cell in which the room is not blocked.
+    ///
+    /// Only if every room is blocked for the whole week does it fall back to any cell.
+    pub fn random_assignment(&self, course_id: usize, rng: &mut impl Rng) -> (u32, u32, usize) {
+        match self.course_rooms[course_id].choose(rng) {
+            Some(&room_id) => {
+                let (day, slot) = *self.open_cells[room_id].choose(rng).expect("usable rooms have open cells");
+                (day, slot, room_id)
+            }
+            None => {
+                let day = rng.gen_range(0..self.day_count);
+                let slot = rng.gen_range(0..self.slot_count);
+                (day, slot, rng.gen_range(0..self.room_count))
+            }
+        }
+    }
+
+    /// Like [`Self::random_assignment`] for session `session_id` of course
+    /// `course_id`, but within its pin if it has one.
+    pub fn random_session_assignment(&self, session_id: usize, course_id: usize, rng: &mut impl Rng) -> (u32, u32, usize) {
+        match self.pins.get(session_id).and_then(Option::as_ref) {
+            Some((_, options)) if !options.is_empty() => *options.choose(rng).expect("options are not empty"),
+            _ => self.random_assignment(course_id, rng),
+        }
+    }
+
+    /// Returns true if a session of `course_id` may use the room of `assignment` at its
+    /// time: the room is in the course's domain and not blocked in that cell.
+    fn fits(&self, input: &TimetableInput, course_id: usize, assignment: Assignment) -> bool {
+        let (day, slot, room_id) = assignment;
+        self.course_rooms[course_id].contains(&room_id) && !input.rooms[room_id].is_unavailable(day, slot)
+    }
+
+    /// Returns true if session `session_id` may take `assignment` (always, unless it is pinned).
+    pub fn allows(&self, session_id: usize, assignment: Assignment) -> bool {
+        self.pins.get(session_id).and_then(Option::as_ref).is_none_or(|(pin, _)| pin.allows(assignment))
+    }
+
+    /// A random session that can be moved; any session if every one is fixed.
+    fn pick_session(&self, schedule: &Schedule, rng: &mut impl Rng) -> usize {
+        match self.movable.choose(rng) {
+            Some(&session_id) => session_id,
+            None => rng.gen_range(0..schedule.assignments.len()),
+        }
+    }
+
+    /// A schedule for `input` with every session at a random assignment.
+    pub fn random_schedule(&self, input: &TimetableInput, rng: &mut impl Rng) -> Schedule {
+        let sessions = Schedule::sessions_for(input);
+        let assignments = sessions.iter()
+            .enumerate()
+            .map(|(session_id, session)| self.random_session_assignment(session_id, session.course_id, rng))
+            .collect();
+        Schedule { sessions, assignments }
+    }
+
+    /// Picks a random session of `schedule` and a random new (Day, Slot, RoomID) for it.
+    pub fn random_move(&self, schedule: &Schedule, rng: &mut impl Rng) -> (usize, (u32, u32, usize)) {
+        let session_id = self.pick_session(schedule, rng);
+        (session_id, self.random_session_assignment(session_id, schedule.sessions[session_id].course_id, rng))
+    }
+
+    /// Draws a move kind from `mix` and builds a move of that kind for the current schedule.
+    ///
+    /// When the kind does not apply (e.g. no other session shares the cell for
+    /// a room swap, or it would move a session against its pin, out of its room
+    /// domain or into a blocked room cell), it falls back to a re-assignment.
+    pub fn sample_move(&self, evaluator: &IncrementalEvaluator, mix: &MoveMix, rng: &mut impl Rng) -> Move {
+        let schedule = evaluator.schedule();
+        let input = evaluator.input();
+        let session_id = self.pick_session(schedule, rng);
+        let (day, slot, room_id) = schedule.assignments[session_id];
+        let course_id = schedule.sessions[session_id].course_id;
+
+        let changes = match mix.pick(rng) {
+            MoveKind::Reassign => None,
+            MoveKind::SwapTimes => {
+                let other = self.pick_session(schedule, rng);
+                let (other_day, other_slot, other_room) = schedule.assignments[other];
+                ((other_day, other_slot) != (day, slot)).then(|| {
+                    (MoveKind::SwapTimes, vec![(session_id, (other_day, other_slot, room_id)), (other, (day, slot, other_room))])
+                })
+            }
+            MoveKind::SwapRooms => {
+                // Each room has to be in the other course's domain
+                let neighbours: Vec<usize> = evaluator.sessions_at(day, slot).iter()
+                    .copied()
+                    .filter(|&other| {
+                        let other_room = schedule.assignments[other].2;
+                        other_room != room_id
+                            && self.fits(input, course_id, (day, slot, other_room))
+                            && self.fits(input, schedule.sessions[other].course_id, (day, slot, room_id))
+                    })
+                    .collect();
+                neighbours.choose(rng).map(|&other| {
+                    let other_room = schedule.assignments[other].2;
+                    (MoveKind::SwapRooms, vec![(session_id, (day, slot, other_room)), (other, (day, slot, room_id))])
+                })
+            }
+            MoveKind::FreeRoom => {
+                let free_rooms: Vec<usize> = self.course_rooms[course_id].iter()
+                    .copied()
+                    .filter(|&room| {
+                        room != room_id && evaluator.is_room_free(day, slot, room) && !input.rooms[room].is_unavailable(day, slot)
+                    })
+                    .collect();
+                free_rooms.choose(rng).map(|&room| (MoveKind::FreeRoom, vec![(session_id, (day, slot, room))]))
+            }
+            MoveKind::KempeChain => {
+                let other_day = rng.gen_range(0..self.day_count);
+                let other_slot = rng.gen_range(0..self.slot_count);
+                ((other_day, other_slot) != (day, slot))
+                    .then(|| self.kempe_chain(evaluator, session_id, (other_day, other_slot)))
+                    .map(|changes| (MoveKind::KempeChain, changes))
+            }
+        };
+
+        // Time swaps and Kempe chains keep the rooms, which may be blocked in the new cell
+        let valid = |&(id, assignment): &(usize, Assignment)| {
+            self.allows(id, assignment) && self.fits(input, schedule.sessions[id].course_id, assignment)
+        };
+        match changes {
+            Some((kind, changes)) if changes.iter().all(valid) => Move { kind, changes },
+            _ => Move {
+                kind: MoveKind::Reassign,
+                changes: vec![(session_id, self.random_session_assignment(session_id, course_id, rng))],
+            },
+        }
+    }
+
+    /// Swaps the Kempe chain of `start` between its cell and `other`.
+    ///
+    /// The chain grows from `start` by adding every session of the opposite
+    /// cell that shares a group or a teacher with a session already in it.
+    /// Every session keeps its room.
+    fn kempe_chain(&self, evaluator: &IncrementalEvaluator, start: usize, other: (u32, u32)) -> Vec<(usize, Assignment)> {
+        let schedule = evaluator.schedule();
+        let (day, slot, _) = schedule.assignments[start];
+        let cells = [(day, slot), other];
+        let shares_entity = |a: usize, b: usize| {
+            let entities = evaluator.course_entities(schedule.sessions[b].course_id);
+            evaluator.course_entities(schedule.sessions[a].course_id).iter().any(|entity| entities.contains(entity))
+        };
+
+        let mut chain = vec![start];
+        let mut side = vec![0];
+        let mut next = 0;
+        while next < chain.len() {
+            let (session_id, from) = (chain[next], side[next]);
+            let (to_day, to_slot) = cells[1 - from];
+            for &candidate in evaluator.sessions_at(to_day, to_slot) {
+                if !chain.contains(&candidate) && shares_entity(session_id, candidate) {
+                    chain.push(candidate);
+                    side.push(1 - from);
+                }
+            }
+            next += 1;
+        }
+
+        chain.into_iter()
+            .zip(side)
+            .map(|(session_id, from)| {
+                let (to_day, to_slot) = cells[1 - from];
+                (session_id, (to_day, to_slot, schedule.assignments[session_id].2))
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,100 @@
+use crate::domain::score::Score;
+use crate::solver::Solver;
+use crate::solver::moves::MoveStats;
+use crate::solver::simulated_annealing::SimulatedAnnealing;
+use crate::solver::stop::RunOutcome;
+use std::sync::Mutex;
+use std::thread;
+use std::time::Instant;
+
+type Assignments = Vec<(u32, u32, usize)>;
+
+/// Runs several independent annealing chains in parallel and keeps the best result.
+///
+/// Chain `i` is seeded with `annealing.seed + i`. Without sharing, a run is as
+/// reproducible as a single chain; with sharing, the restart points depend on
+/// thread timing.
+#[derive(Debug, Clone)]
+pub struct MultiStart {
+    /// Settings (temperature, stop condition, base seed) used by every chain.
+    pub annealing: SimulatedAnnealing,
+    /// Number of chains, one thread each.
+    pub chains: usize,
+    /// Every how many iterations chains exchange their best schedule. `None` keeps them independent.
+    pub share_interval: Option<u64>,
+}
+
+impl MultiStart {
+    pub fn new(annealing: SimulatedAnnealing, chains: usize, share_interval: Option<u64>) -> Self {
+        Self { annealing, chains, share_interval }
+    }
+
+    /// Runs all chains and returns the outcome of the best one.
+    ///
+    /// Ties go to the lowest chain. `iterations` and `move_stats` are summed
+    /// over all chains, `elapsed` is the wall-clock time of the whole run.
+    pub fn solve(&self) -> RunOutcome {
+        let started = Instant::now();
+        let shared = SharedBest::default();
+        let sharing = self.share_interval.filter(|&interval| interval > 0).map(|interval| (&shared, interval));
+
+        let outcomes: Vec<RunOutcome> = thread::scope(|scope| {
+            let handles: Vec<_> = (0..self.chains.max(1) as u64)
+                .map(|chain| {
+                    let seed = self.annealing.seed.wrapping_add(chain);
+                    scope.spawn(move || self.annealing.solve_chain(seed, sharing))
+                })
+                .collect();
+            handles.into_iter().map(|handle| handle.join().expect("annealing chain panicked")).collect()
+        });
+
+        let iterations = outcomes.iter().map(|outcome| outcome.iterations).sum();
+        let mut move_stats = MoveStats::default();
+        for outcome in &outcomes {
+            move_stats.merge(&outcome.move_stats);
+        }
+        let mut best = outcomes.into_iter()
+            .reduce(|best, outcome| if outcome.penalty < best.penalty { outcome } else { best })
+            .expect("at least one chain runs");
+        best.iterations = iterations;
+        best.elapsed = started.elapsed();
+        best.move_stats = move_stats;
+        best
+    }
+}
+
+/// The best schedule found so far by any chain.
+#[derive(Debug, Default)]
+pub(crate) struct SharedBest {
+    best: Mutex<Option<(Score, Assignments)>>,
+}
+
+impl SharedBest {
+    /// Publishes a chain's best schedule.
+    ///
+    /// Returns the global best when it is strictly better than `penalty`,
+    /// otherwise stores `assignments` if they are the new global best.
+    pub(crate) fn exchange(&self, penalty: Score, assignments: &[(u32, u32, usize)]) -> Option<(Score, Assignments)> {
+        let mut best = self.best.lock().expect("shared best lock poisoned");
+        match &*best {
+            Some((best_penalty, best_assignments)) if *best_penalty < penalty => {
+                Some((*best_penalty, best_assignments.clone()))
+            }
+            Some((best_penalty, _)) if *best_penalty == penalty => None,
+            _ => {
+                *best = Some((penalty, assignments.to_vec()));
+                None
+            }
+        }
+    }
+}
+
+impl Solver for MultiStart {
+    fn name(&self) -> &'static str {
+        "simulated-annealing"
+    }
+
+    fn solve(&self) -> RunOutcome {
+        MultiStart::solve(self)
+    }
+}
@@ -0,0 +1,87 @@
+use crate::domain::{config::Config, input_wrapper::TimetableInput};
+use crate::solver::Solver;
+use crate::solver::genetic::GeneticAlgorithm;
+use crate::solver::multi_start::MultiStart;
+use crate::solver::simulated_annealing::SimulatedAnnealing;
+use crate::solver::stop::StopCondition;
+use crate::solver::tabu_search::TabuSearch;
+
+/// Algorithm used when `Config::algorithm` is not set.
+pub const DEFAULT_SOLVER: &str = "simulated-annealing";
+
+/// Builds a solver from the input, the stop condition, the seed and its own settings in `Config`.
+type Factory = fn(TimetableInput, StopCondition, u64, &Config) -> Box<dyn Solver>;
+
+/// Every available algorithm. Adding a solver only needs a new row here.
+const SOLVERS: &[(&str, Factory)] = &[
+    ("simulated-annealing", build_simulated_annealing),
+    ("tabu-search", build_tabu_search),
+    ("genetic", build_genetic),
+];
+
+/// Names of all registered solvers, for help and error messages.
+pub fn solver_names() -> Vec<&'static str> {
+    SOLVERS.iter().map(|&(name, _)| name).collect()
+}
+
+/// Builds the solver registered as `name`.
+///
+/// # Errors
+/// Returns a message listing the valid names if `name` is unknown.
+pub fn create_solver(
+    name: &str,
+    input: TimetableInput,
+    stop: StopCondition,
+    seed: u64,
+    config: &Config,
+) -> Result<Box<dyn Solver>, String> {
+    let (_, factory) = SOLVERS.iter().find(|&&(solver, _)| solver == name).ok_or_else(|| {
+        format!("unknown algorithm '{}', expected one of: {}", name, solver_names().join(", "))
+    })?;
+    Ok(factory(input, stop, seed, config))
+}
+
+/// Simulated annealing; runs `Config::chains` chains in parallel when more than one is asked for.
+fn build_simulated_annealing(input: TimetableInput, stop: StopCondition, seed: u64, config: &Config) -> Box<dyn Solver> {
+    let mut annealing = SimulatedAnnealing::new(input, config.start_temp, config.cooling_rate, stop.max_iterations, seed);
+    annealing.stop = stop;
+    annealing.initial = config.initial_solution;
+    annealing.move_mix = config.move_mix.clone();
+    annealing.target_acceptance = config.target_acceptance;
+    annealing.final_temp = config.final_temp;
+    annealing.reheat_after = config.reheat_after;
+    if let Some(reheat_ratio) = config.reheat_ratio {
+        annealing.reheat_ratio = reheat_ratio;
+    }
+
+    match config.chains {
+        Some(chains) if chains > 1 => Box::new(MultiStart::new(annealing, chains, config.share_interval)),
+        _ => Box::new(annealing),
+    }
+}
+
+/// Tabu search; `Config::tabu_tenure` and `Config::tabu_sample_size` default to 20 and 50.
+fn build_tabu_search(input: TimetableInput, stop: StopCondition, seed: u64, config: &Config) -> Box<dyn Solver> {
+    let tenure = config.tabu_tenure.unwrap_or(20);
+    let sample_size = config.tabu_sample_size.unwrap_or(50);
+    let mut tabu = TabuSearch::new(input, tenure, sample_size, stop, seed);
+    tabu.initial = config.initial_solution;
+    tabu.move_mix = config.move_mix.clone();
+    Box::new(tabu)
+}
+
+/// Genetic algorithm; memetic when `Config::local_search_steps` is above 0.
+fn build_genetic(input: TimetableInput, stop: StopCondition, seed: u64, config: &Config) -> Box<dyn Solver> {
+    let mut genetic = GeneticAlgorithm::new(input, config.population_size.unwrap_or(30), stop, seed);
+    genetic.initial = config.initial_solution;
+    if let Some(tournament_size) = config.tournament_size {
+        genetic.tournament_size = tournament_size;
+    }
+    if let Some(mutation_rate) = config.mutation_rate {
+        genetic.mutation_rate = mutation_rate;
+    }
+    if let Some(local_search_steps) = config.local_search_steps {
+        genetic.local_search_steps = local_search_steps;
+    }
+    Box::new(genetic)
+}
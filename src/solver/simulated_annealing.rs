@@ -1,92 +1,219 @@
 use crate::domain::{input_wrapper::TimetableInput, schedule::Schedule};
-use rand::Rng;
+use crate::solver::Solver;
+use crate::solver::construct::InitialSolution;
+use crate::solver::evaluator::IncrementalEvaluator;
+use crate::solver::moves::{MoveGenerator, MoveMix, MoveStats};
+use crate::solver::multi_start::SharedBest;
+use crate::solver::stop::{CLOCK_CHECK_INTERVAL, RunOutcome, StopCondition};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::time::Instant;
+
+/// Moves sampled to estimate the start temperature.
+const TEMPERATURE_SAMPLES: usize = 500;
+/// Iterations between two updates of a cooling rate derived from the budget.
+const COOLING_UPDATE_INTERVAL: u64 = 1024;
 
 #[derive(Debug, Clone)]
 pub struct SimulatedAnnealing{
     pub input: TimetableInput,
     pub start_temp: f64,
     pub cooling_rate: f64,
-    pub max_iterations: u32,
+    /// If set, `start_temp` is replaced by an estimate at which this share of
+    /// the worsening moves of the first schedule would be accepted.
+    pub target_acceptance: Option<f64>,
+    /// If set, `cooling_rate` is replaced by one derived from the remaining
+    /// budget (iterations, or time when limited), so the temperature reaches
+    /// `final_temp` when the budget runs out.
+    pub final_temp: Option<f64>,
+    /// Reheat after this many iterations without a new best schedule (or since the last reheat).
+    pub reheat_after: Option<u32>,
+    /// A reheat sets the temperature back to this share of the start temperature.
+    pub reheat_ratio: f64,
+    /// When to stop; `new` only sets the iteration limit.
+    pub stop: StopCondition,
+    /// Seeds the only RNG of a run: the same input and seed always give the same schedule.
+    pub seed: u64,
+    /// How the starting schedule is built (random when created with `new`).
+    pub initial: InitialSolution,
+    /// How often each move kind is tried.
+    pub move_mix: MoveMix,
+    moves: MoveGenerator,
 }
 
 impl SimulatedAnnealing{
-    pub fn new(input: TimetableInput, start_temp: f64, cooling_rate: f64, max_iterations: u32) -> Self {
+    pub fn new(input: TimetableInput, start_temp: f64, cooling_rate: f64, max_iterations: u32, seed: u64) -> Self {
+        let moves = MoveGenerator::new(&input);
         Self {
             input,
             start_temp,
             cooling_rate,
-            max_iterations,
+            target_acceptance: None,
+            final_temp: None,
+            reheat_after: None,
+            reheat_ratio: 0.5,
+            stop: StopCondition::new(max_iterations),
+            seed,
+            initial: InitialSolution::Random,
+            move_mix: MoveMix::default(),
+            moves,
         }
     }
 
-    fn generate_first_schedule(&self) -> Schedule{
-        let mut rng = rand::thread_rng();
-        let mut assignments: Vec<(u32, u32, usize)> = Vec::new();
+    /// The temperature a chain starts at.
+    ///
+    /// `start_temp`, unless `target_acceptance` is set: then a sample of moves
+    /// is tried (and undone) on `current`, and the start temperature is the one
+    /// at which their average worsening is accepted with that probability.
+    pub fn start_temperature(&self, current: &mut IncrementalEvaluator, rng: &mut impl Rng) -> f64{
+        let Some(acceptance) = self.target_acceptance.filter(|&p| p > 0.0 && p < 1.0) else {
+            return self.start_temp;
+        };
+        if current.schedule().assignments.is_empty() {
+            return self.start_temp;
+        }
 
-        for _ in 0..self.input.courses.len(){
-            let day: u32 = rng.gen_range(0..5);
-            let slot: u32 = rng.gen_range(0..6);
-            let room_id: usize = rng.gen_range(0..self.input.rooms.len()); 
-            assignments.push((day, slot, room_id));
+        let (mut total, mut count) = (0.0, 0u32);
+        for _ in 0..TEMPERATURE_SAMPLES{
+            let neighbour = self.moves.sample_move(current, &self.move_mix, rng);
+            let undo = neighbour.undo(current.schedule());
+            let before = current.penalty();
+            let after = current.apply_moves(&neighbour.changes);
+            current.apply_moves(&undo);
+            if after > before{
+                total += after.worsening_from(before);
+                count += 1;
+            }
         }
+        if count == 0 {
+            return self.start_temp;
+        }
+        -(total / f64::from(count)) / acceptance.ln()
+    }
 
-        Schedule { assignments }
+    /// The cooling rate to use from `iteration` on, at temperature `temp`.
+    ///
+    /// `cooling_rate`, unless `final_temp` is set: then it is the rate that
+    /// reaches `final_temp` in the iterations left, which with a time limit are
+    /// estimated from the speed so far.
+    pub fn derived_cooling_rate(&self, temp: f64, iteration: u64, started: Instant) -> f64{
+        let Some(final_temp) = self.final_temp.filter(|&t| t > 0.0) else {
+            return self.cooling_rate;
+        };
+        let mut remaining = u64::from(self.stop.max_iterations).saturating_sub(iteration) as f64;
+        if let Some(limit) = self.stop.time_limit {
+            let elapsed = started.elapsed().as_secs_f64();
+            if iteration > 0 && elapsed > 0.0 {
+                let per_second = iteration as f64 / elapsed;
+                remaining = remaining.min(per_second * (limit.as_secs_f64() - elapsed).max(0.0));
+            }
+        }
+        if remaining < 1.0 || temp <= final_temp {
+            return 1.0;
+        }
+        ((final_temp / temp).ln() / remaining).exp()
     }
 
     pub fn run(&self) -> Schedule{
-        let mut rng = rand::thread_rng();
-        let mut current_assignments: Schedule = self.generate_first_schedule();
-        let mut current_penalty: u32 = current_assignments.calculate_penalty(&self.input);
+        self.solve().schedule
+    }
 
-        let mut best_schedule = self.generate_first_schedule();
+    /// Runs the annealing until `self.stop` is met and reports why it stopped.
+    pub fn solve(&self) -> RunOutcome{
+        self.solve_chain(self.seed, None)
+    }
 
-        best_schedule.assignments = current_assignments.assignments.clone();
+    /// One annealing chain seeded with `seed`.
+    ///
+    /// With `sharing`, every `interval` iterations the chain publishes its best
+    /// schedule and restarts from the global best if another chain found a better one.
+    pub(crate) fn solve_chain(&self, seed: u64, sharing: Option<(&SharedBest, u64)>) -> RunOutcome{
+        let started = Instant::now();
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut current = IncrementalEvaluator::new(&self.input, self.initial.build(&self.input, &self.moves, &mut rng));
+        let mut current_penalty = current.penalty();
+
+        let mut best_schedule = current.schedule().clone();
         let mut best_penalty = current_penalty;
 
-        let mut temp = self.start_temp;
-        for _ in 0..self.max_iterations{
-            let neighbour_schedule = self.generate_neighbour(&current_assignments);
-            let neighbour_penalty = neighbour_schedule.calculate_penalty(&self.input);
+        let start_temp = self.start_temperature(&mut current, &mut rng);
+        let mut temp = start_temp;
+        let mut cooling_rate = self.derived_cooling_rate(temp, 0, started);
+        let mut iteration: u64 = 0;
+        let mut last_improvement: u64 = 0;
+        let mut last_reheat: u64 = 0;
+        let mut move_stats = MoveStats::default();
+        let stop_reason = loop{
+            if let Some(reason) = self.stop.check(iteration, started, iteration - last_improvement, best_penalty, CLOCK_CHECK_INTERVAL){
+                break reason;
+            }
+            iteration += 1;
+
+            if let Some(reheat_after) = self.reheat_after
+                && iteration - last_improvement.max(last_reheat) >= u64::from(reheat_after)
+            {
+                temp = start_temp * self.reheat_ratio;
+                last_reheat = iteration;
+                cooling_rate = self.derived_cooling_rate(temp, iteration, started);
+            } else if iteration.is_multiple_of(COOLING_UPDATE_INTERVAL) {
+                cooling_rate = self.derived_cooling_rate(temp, iteration, started);
+            }
 
-            if neighbour_penalty == 0{
-                return neighbour_schedule;
+            if let Some((shared, interval)) = sharing
+                && iteration.is_multiple_of(interval)
+                && let Some((penalty, assignments)) = shared.exchange(best_penalty, &best_schedule.assignments)
+            {
+                best_schedule.assignments = assignments;
+                best_penalty = penalty;
+                current = IncrementalEvaluator::new(&self.input, best_schedule.clone());
+                current_penalty = best_penalty;
+                last_improvement = iteration;
             }
 
-            let diff = (current_penalty as i64) - (neighbour_penalty as i64);
+            let neighbour = self.moves.sample_move(&current, &self.move_mix, &mut rng);
+            let undo = neighbour.undo(current.schedule());
+            let neighbour_penalty = current.apply_moves(&neighbour.changes);
 
-            let should_change = if diff > 0{
+            let should_change = if neighbour_penalty < current_penalty{
                 true
                 }
                 else{
-                    let probability = (diff as f64 / temp).exp();
+                    let probability = (-neighbour_penalty.worsening_from(current_penalty) / temp).exp();
                     let random_probability: f64 = rng.r#gen::<f64>();
                     random_probability < probability
             };
+            move_stats.record(neighbour.kind, should_change);
             if should_change {
-                current_assignments.assignments = neighbour_schedule.assignments;
                 current_penalty = neighbour_penalty;
 
                 if best_penalty > current_penalty{
-                    best_schedule.assignments = current_assignments.assignments.clone();
+                    best_schedule.assignments.clone_from(&current.schedule().assignments);
                     best_penalty = current_penalty;
+                    last_improvement = iteration;
                 }
+            } else {
+                current.apply_moves(&undo);
             }
-            temp *= self.cooling_rate;
+            temp *= cooling_rate;
+        };
+
+        RunOutcome {
+            schedule: best_schedule,
+            penalty: best_penalty,
+            iterations: iteration,
+            elapsed: started.elapsed(),
+            stop_reason,
+            move_stats,
         }
-        best_schedule
     }
+}
 
-    fn generate_neighbour(&self, current_assignments: &Schedule) -> Schedule{
-        let mut rng = rand::thread_rng();
-
-        let rand_course_id = rng.gen_range(0..current_assignments.assignments.len());
-        let day = rng.gen_range(0..5);
-        let slot = rng.gen_range(0..6);
-        let room_id = rng.gen_range(0..self.input.rooms.len());
-
-        let mut neighbour_assignments = current_assignments.assignments.clone();
-        neighbour_assignments[rand_course_id] = (day, slot, room_id);
+impl Solver for SimulatedAnnealing{
+    fn name(&self) -> &'static str{
+        "simulated-annealing"
+    }
 
-        Schedule { assignments: neighbour_assignments }
+    fn solve(&self) -> RunOutcome{
+        SimulatedAnnealing::solve(self)
     }
 }
@@ -0,0 +1,104 @@
+use crate::domain::{config::Config, schedule::Schedule, score::Score};
+use crate::solver::moves::MoveStats;
+use serde::Serialize;
+use std::fmt;
+use std::time::{Duration, Instant};
+
+/// How often (in iterations) solvers with cheap iterations read the wall clock, so the time
+/// limit costs nothing in the hot loop.
+pub const CLOCK_CHECK_INTERVAL: u64 = 1024;
+
+/// When a solver run should stop. The first criterion that is met wins.
+#[derive(Debug, Clone, PartialEq)]
+pub struct StopCondition {
+    pub max_iterations: u32,
+    /// Wall-clock budget of the run.
+    pub time_limit: Option<Duration>,
+    /// Stop after this many iterations in a row without a new best schedule.
+    pub max_no_improvement: Option<u32>,
+    /// Stop once the best penalty is at or below this score.
+    ///
+    /// A target with only soft points means "every hard and medium constraint
+    /// holds and the soft penalty is at most this". Defaults to 0: a perfect schedule.
+    pub target_penalty: Score,
+}
+
+/// Which criterion of a [`StopCondition`] ended the run.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub enum StopReason {
+    TargetReached,
+    MaxIterations,
+    TimeLimit,
+    NoImprovement,
+}
+
+impl fmt::Display for StopReason {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let text = match self {
+            StopReason::TargetReached => "target penalty reached",
+            StopReason::MaxIterations => "iteration limit reached",
+            StopReason::TimeLimit => "time limit reached",
+            StopReason::NoImprovement => "no improvement for too long",
+        };
+        write!(f, "{}", text)
+    }
+}
+
+/// The result of a solver run.
+#[derive(Debug, Clone)]
+pub struct RunOutcome {
+    /// The best schedule found.
+    pub schedule: Schedule,
+    pub penalty: Score,
+    pub iterations: u64,
+    pub elapsed: Duration,
+    pub stop_reason: StopReason,
+    /// Proposed and accepted moves per kind (empty for solvers that do not use the move mix).
+    pub move_stats: MoveStats,
+}
+
+impl StopCondition {
+    /// Only stops on `max_iterations` or a perfect schedule.
+    pub fn new(max_iterations: u32) -> Self {
+        Self { max_iterations, time_limit: None, max_no_improvement: None, target_penalty: Score::ZERO }
+    }
+
+    /// Reads every stop criterion from `config`.
+    ///
+    /// A `time_limit_secs` that is not a valid duration (see `Config::validate`) sets no time limit.
+    pub fn from_config(config: &Config) -> Self {
+        Self {
+            max_iterations: config.max_iterations,
+            time_limit: config.time_limit_secs.and_then(|seconds| Duration::try_from_secs_f64(seconds).ok()),
+            max_no_improvement: config.max_no_improvement,
+            target_penalty: Score::soft(config.target_penalty.unwrap_or(0)),
+        }
+    }
+
+    /// Checks every criterion before running iteration number `iteration`.
+    ///
+    /// # Arguments
+    /// * `started` - When the run began.
+    /// * `since_improvement` - Iterations since the best penalty last went down.
+    /// * `best_penalty` - The best penalty found so far.
+    /// * `clock_interval` - The wall clock is only read every this many iterations; solvers with
+    ///   costly iterations pass 1.
+    pub fn check(&self, iteration: u64, started: Instant, since_improvement: u64, best_penalty: Score, clock_interval: u64) -> Option<StopReason> {
+        if best_penalty <= self.target_penalty {
+            return Some(StopReason::TargetReached);
+        }
+        if iteration >= u64::from(self.max_iterations) {
+            return Some(StopReason::MaxIterations);
+        }
+        if self.max_no_improvement.is_some_and(|limit| since_improvement >= u64::from(limit)) {
+            return Some(StopReason::NoImprovement);
+        }
+        if let Some(limit) = self.time_limit
+            && iteration.is_multiple_of(clock_interval.max(1))
+            && started.elapsed() >= limit
+        {
+            return Some(StopReason::TimeLimit);
+        }
+        None
+    }
+}
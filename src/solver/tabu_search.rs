@@ -0,0 +1,127 @@
+use crate::domain::input_wrapper::TimetableInput;
+use crate::solver::Solver;
+use crate::solver::construct::InitialSolution;
+use crate::solver::evaluator::IncrementalEvaluator;
+use crate::solver::moves::{MoveGenerator, MoveMix, MoveStats};
+use crate::solver::stop::{CLOCK_CHECK_INTERVAL, RunOutcome, StopCondition};
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Instant;
+
+/// Tabu search over the moves of `move_mix`.
+///
+/// Every iteration samples `sample_size` random moves and takes the best one
+/// that is not tabu, even if it makes the schedule worse. When a session of a
+/// course leaves a (Day, Slot), moving that course back into it is tabu for
+/// `tenure` iterations, unless the move beats the best penalty found so far
+/// (aspiration). A compound move is tabu if any of its sessions is.
+///
+/// Candidates are scored by an `IncrementalEvaluator`, which always agrees with
+/// `Schedule::calculate_penalty`.
+#[derive(Debug, Clone)]
+pub struct TabuSearch {
+    pub input: TimetableInput,
+    /// Iterations a (course, slot) attribute stays tabu.
+    pub tenure: u64,
+    /// Number of random moves evaluated per iteration.
+    pub sample_size: usize,
+    pub stop: StopCondition,
+    pub seed: u64,
+    /// How the starting schedule is built (random when created with `new`).
+    pub initial: InitialSolution,
+    /// How often each move kind is sampled.
+    pub move_mix: MoveMix,
+    moves: MoveGenerator,
+}
+
+impl TabuSearch {
+    pub fn new(input: TimetableInput, tenure: u64, sample_size: usize, stop: StopCondition, seed: u64) -> Self {
+        let moves = MoveGenerator::new(&input);
+        Self { input, tenure, sample_size, stop, seed, initial: InitialSolution::Random, move_mix: MoveMix::default(), moves }
+    }
+
+    /// Runs the search until `self.stop` is met and reports why it stopped.
+    pub fn solve(&self) -> RunOutcome {
+        let started = Instant::now();
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut current = IncrementalEvaluator::new(&self.input, self.initial.build(&self.input, &self.moves, &mut rng));
+
+        let mut best_schedule = current.schedule().clone();
+        let mut best_penalty = current.penalty();
+
+        // [CourseID][Day * slot_count + Slot] -> first iteration at which the attribute is free again.
+        let slot_count = self.input.week.slot_count();
+        let mut tabu_until = vec![vec![0u64; self.input.week.cell_count()]; self.input.courses.len()];
+
+        // Every iteration evaluates `sample_size` moves, so the clock is read about every CLOCK_CHECK_INTERVAL moves
+        let clock_interval = (CLOCK_CHECK_INTERVAL / self.sample_size.max(1) as u64).max(1);
+        let mut iteration: u64 = 0;
+        let mut last_improvement: u64 = 0;
+        let mut move_stats = MoveStats::default();
+        let stop_reason = loop {
+            if let Some(reason) = self.stop.check(iteration, started, iteration - last_improvement, best_penalty, clock_interval) {
+                break reason;
+            }
+            iteration += 1;
+
+            let mut chosen = None;
+            for _ in 0..self.sample_size.max(1) {
+                let candidate = self.moves.sample_move(&current, &self.move_mix, &mut rng);
+                let undo = candidate.undo(current.schedule());
+                if candidate.changes.iter().all(|&(session_id, assignment)| current.schedule().assignments[session_id] == assignment) {
+                    continue;
+                }
+                move_stats.record(candidate.kind, false);
+                let penalty = current.apply_moves(&candidate.changes);
+                current.apply_moves(&undo);
+
+                let is_tabu = candidate.changes.iter().any(|&(session_id, (day, slot, _))| {
+                    let course_id = current.schedule().sessions[session_id].course_id;
+                    tabu_until[course_id][day as usize * slot_count + slot as usize] > iteration
+                });
+                if is_tabu && penalty >= best_penalty {
+                    continue;
+                }
+                if chosen.as_ref().is_none_or(|(_, chosen_penalty)| penalty < *chosen_penalty) {
+                    chosen = Some((candidate, penalty));
+                }
+            }
+
+            let Some((chosen, _)) = chosen else {
+                continue;
+            };
+            move_stats.record_accepted(chosen.kind);
+            for &(session_id, _) in &chosen.changes {
+                let (day, slot, _) = current.schedule().assignments[session_id];
+                let course_id = current.schedule().sessions[session_id].course_id;
+                tabu_until[course_id][day as usize * slot_count + slot as usize] = iteration + self.tenure;
+            }
+
+            let penalty = current.apply_moves(&chosen.changes);
+            if penalty < best_penalty {
+                best_schedule.assignments.clone_from(&current.schedule().assignments);
+                best_penalty = penalty;
+                last_improvement = iteration;
+            }
+        };
+
+        RunOutcome {
+            schedule: best_schedule,
+            penalty: best_penalty,
+            iterations: iteration,
+            elapsed: started.elapsed(),
+            stop_reason,
+            move_stats,
+        }
+    }
+}
+
+impl Solver for TabuSearch {
+    fn name(&self) -> &'static str {
+        "tabu-search"
+    }
+
+    fn solve(&self) -> RunOutcome {
+        TabuSearch::solve(self)
+    }
+}
@@ -0,0 +1,74 @@
+#![allow(dead_code)] // Each test file uses only some of the helpers
+
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use UCTP::domain::{course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::moves::{MoveGenerator, MoveMix};
+
+/// The required keys of `config.toml`, for tests that parse a config with extra tables.
+pub const BASE_CONFIG: &str = "start_temp = 100.0\ncooling_rate = 0.99\nmax_iterations = 400\nfile_name = \"input.json\"\n";
+
+/// Applies 2000 random moves to a random schedule and checks the evaluator's
+/// running penalty against a full recalculation after each of them.
+pub fn assert_evaluator_matches(input: &TimetableInput, seed: u64) {
+    let moves = MoveGenerator::new(input);
+    let mut rng = StdRng::seed_from_u64(seed);
+    let mut evaluator = IncrementalEvaluator::new(input, moves.random_schedule(input, &mut rng));
+    assert_eq!(evaluator.penalty(), evaluator.schedule().calculate_penalty(input));
+
+    for _ in 0..2000 {
+        let neighbour = moves.sample_move(&evaluator, &MoveMix::default(), &mut rng);
+        let penalty = evaluator.apply_moves(&neighbour.changes);
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(input), "Delta and full penalty diverged");
+    }
+}
+
+// --- Helper: Two buildings, one group, one teacher, a lecture and a lab ---
+pub fn create_lecture_and_lab_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![
+            Room { id: 301, name: "Hall".to_string(), capacity: 100, is_laboratory: false, building_id: 1, unavailable: vec![(4, 0)] },
+            Room { id: 302, name: "Lab".to_string(), capacity: 20, is_laboratory: true, building_id: 2, unavailable: vec![] },
+        ],
+        groups: vec![Group { id: 555, name: "G1".to_string(), numbers_of_students: 30, courses: vec![0, 1] }],
+        courses: vec![
+            Course {
+                id: 1001, subject_name: "Math".to_string(), professor_id: 10,
+                group_ids: vec![0], required_hours: 2, required_lab: false,
+            },
+            Course {
+                id: 2002, subject_name: "Physics Lab".to_string(), professor_id: 10,
+                group_ids: vec![0], required_hours: 2, required_lab: true,
+            },
+        ],
+        teachers: vec![Teacher { id: 10, name: "Prof. X".to_string(), course_id: vec![0, 1], ..Default::default() }],
+        ..Default::default()
+    }
+}
+
+// --- Helper: Raw input with "random" Database IDs, before `normalize_data` ---
+pub fn create_raw_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![
+            Room { id: 301, name: "C309".to_string(), capacity: 60, is_laboratory: false, building_id: 0, unavailable: vec![] },
+            Room { id: 302, name: "L1".to_string(), capacity: 60, is_laboratory: true, building_id: 0, unavailable: vec![] },
+        ],
+        teachers: vec![Teacher { id: 10, name: "Prof. X".to_string(), course_id: vec![1001, 2002], ..Default::default() }],
+        courses: vec![
+            Course {
+                id: 1001, subject_name: "Math".to_string(), professor_id: 10,
+                group_ids: vec![555, 777], required_hours: 4, required_lab: false,
+            },
+            Course {
+                id: 2002, subject_name: "Physics".to_string(), professor_id: 10,
+                group_ids: vec![555], required_hours: 2, required_lab: true,
+            },
+        ],
+        groups: vec![
+            Group { id: 555, name: "Group A".to_string(), numbers_of_students: 25, courses: vec![1001, 2002] },
+            Group { id: 777, name: "Group B".to_string(), numbers_of_students: 30, courses: vec![1001] },
+        ],
+        ..Default::default()
+    }
+}
@@ -0,0 +1,101 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule, teacher::Teacher,
+};
+use UCTP::solver::construct::{greedy_schedule, InitialSolution};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: A 210-student lecture, labs and seminars competing for few rooms ---
+fn create_faculty_input() -> TimetableInput {
+    let rooms = vec![
+        Room { id: 1, name: "Amphitheater".to_string(), capacity: 250, is_laboratory: false, building_id: 1, unavailable: vec![] },
+        Room { id: 2, name: "Seminar".to_string(), capacity: 40, is_laboratory: false, building_id: 1, unavailable: vec![] },
+        Room { id: 3, name: "Lab".to_string(), capacity: 40, is_laboratory: true, building_id: 2, unavailable: vec![(0, 0)] },
+    ];
+
+    let mut courses = vec![Course {
+        id: 100, subject_name: "Lecture".to_string(), professor_id: 0,
+        group_ids: (0..7).collect(), required_hours: 4, required_lab: false,
+    }];
+    for g in 0..7 {
+        courses.push(Course {
+            id: 200 + g, subject_name: format!("Seminar G{}", g), professor_id: 1,
+            group_ids: vec![g], required_hours: 2, required_lab: false,
+        });
+        courses.push(Course {
+            id: 300 + g, subject_name: format!("Lab G{}", g), professor_id: 2,
+            group_ids: vec![g], required_hours: 2, required_lab: true,
+        });
+    }
+
+    TimetableInput {
+        rooms,
+        groups: (0..7)
+            .map(|g| Group { id: g, name: format!("G{}", g), numbers_of_students: 30, courses: vec![0, 1 + 2 * g, 2 + 2 * g] })
+            .collect(),
+        courses,
+        teachers: vec![
+            Teacher { id: 0, name: "Lecturer".to_string(), course_id: vec![0], ..Default::default() },
+            Teacher { id: 1, name: "Seminar Prof".to_string(), course_id: (0..7).map(|g| 1 + 2 * g).collect(), ..Default::default() },
+            Teacher { id: 2, name: "Lab Prof".to_string(), course_id: (0..7).map(|g| 2 + 2 * g).collect(), ..Default::default() },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_greedy_schedule_has_no_hard_violations() {
+    let input = create_faculty_input();
+    let schedule = greedy_schedule(&input);
+
+    assert_eq!(schedule.sessions, Schedule::sessions_for(&input), "Sessions keep the canonical order");
+    assert!(schedule.calculate_penalty(&input).is_feasible(), "Got {}", schedule.penalty_report(&input));
+
+    // The 210-student lecture is in the amphitheater, the labs are in the lab
+    for (session, &(_, _, room_id)) in schedule.sessions.iter().zip(&schedule.assignments) {
+        let course = &input.courses[session.course_id];
+        if course.group_ids.len() == 7 {
+            assert_eq!(room_id, 0);
+        }
+        if course.required_lab {
+            assert_eq!(room_id, 2);
+        }
+    }
+}
+
+#[test]
+fn test_greedy_schedule_is_deterministic() {
+    let input = create_faculty_input();
+    assert_eq!(greedy_schedule(&input).assignments, greedy_schedule(&input).assignments);
+}
+
+#[test]
+fn test_greedy_start_beats_random_start() {
+    let input = create_faculty_input();
+
+    // 0 iterations: the solvers return their starting schedule
+    let mut greedy = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 0, 1);
+    greedy.initial = InitialSolution::Greedy;
+    let random = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 0, 1);
+
+    assert!(greedy.solve().penalty < random.solve().penalty);
+}
+
+#[test]
+fn test_evaluator_add_session_matches_full_penalty() {
+    let input = create_faculty_input();
+    let full = greedy_schedule(&input);
+
+    let mut evaluator = IncrementalEvaluator::new(&input, Schedule { sessions: vec![], assignments: vec![] });
+    for (&session, &assignment) in full.sessions.iter().zip(&full.assignments) {
+        let penalty = evaluator.add_session(session, assignment);
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(&input));
+    }
+}
+
+#[test]
+fn test_initial_solution_names() {
+    assert_eq!("greedy".parse::<InitialSolution>(), Ok(InitialSolution::Greedy));
+    assert_eq!("random".parse::<InitialSolution>(), Ok(InitialSolution::Random));
+    assert!("clever".parse::<InitialSolution>().is_err());
+}
@@ -0,0 +1,121 @@
+mod common;
+
+use common::BASE_CONFIG;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::{Duration, Instant};
+use UCTP::domain::{config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::moves::MoveGenerator;
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: Five groups with two courses each, two rooms in two buildings ---
+fn create_cooling_input() -> TimetableInput {
+    TimetableInput {
+        rooms: (0..2)
+            .map(|id| Room { id, name: format!("Room {}", id), capacity: 40, is_laboratory: false, building_id: id, unavailable: vec![] })
+            .collect(),
+        groups: (0..5)
+            .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: 30, courses: vec![id, id + 5] })
+            .collect(),
+        courses: (0..10)
+            .map(|id| Course {
+                id: 100 + id, subject_name: format!("Course {}", id), professor_id: id % 2,
+                group_ids: vec![id % 5], required_hours: 4, required_lab: false,
+            })
+            .collect(),
+        teachers: (0..2)
+            .map(|id| Teacher { id, name: format!("Prof. {}", id), course_id: (id..10).step_by(2).collect(), ..Default::default() })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn estimate(sa: &SimulatedAnnealing) -> f64 {
+    let mut rng = StdRng::seed_from_u64(1);
+    let schedule = MoveGenerator::new(&sa.input).random_schedule(&sa.input, &mut rng);
+    let mut evaluator = IncrementalEvaluator::new(&sa.input, schedule);
+    let before = evaluator.schedule().assignments.clone();
+
+    let temp = sa.start_temperature(&mut evaluator, &mut rng);
+    assert_eq!(evaluator.schedule().assignments, before, "Sampling must leave the schedule untouched");
+    temp
+}
+
+#[test]
+fn test_start_temperature_is_estimated_from_moves() {
+    let mut sa = SimulatedAnnealing::new(create_cooling_input(), 42.0, 0.99, 1000, 1);
+    assert_eq!(estimate(&sa), 42.0, "Without a target acceptance the configured value is used");
+
+    sa.target_acceptance = Some(0.5);
+    let lukewarm = estimate(&sa);
+    sa.target_acceptance = Some(0.9);
+    let hot = estimate(&sa);
+
+    assert!(lukewarm > 42.0, "Worsening moves cost hard-constraint points, got {}", lukewarm);
+    assert!(hot > lukewarm, "A higher acceptance needs a higher temperature");
+}
+
+#[test]
+fn test_cooling_rate_is_derived_from_iteration_budget() {
+    let mut sa = SimulatedAnnealing::new(create_cooling_input(), 1000.0, 0.5, 1000, 1);
+    let started = Instant::now();
+    assert_eq!(sa.derived_cooling_rate(1000.0, 0, started), 0.5);
+
+    sa.final_temp = Some(1.0);
+    let rate = sa.derived_cooling_rate(1000.0, 0, started);
+    assert!((1000.0 * rate.powi(1000) - 1.0).abs() < 1e-6, "Ends at the final temperature");
+
+    let halfway = sa.derived_cooling_rate(1000.0, 500, started);
+    assert!(halfway < rate, "Fewer iterations left means faster cooling");
+    assert_eq!(sa.derived_cooling_rate(0.5, 0, started), 1.0, "Below the final temperature it holds");
+}
+
+#[test]
+fn test_cooling_rate_follows_time_budget() {
+    let mut sa = SimulatedAnnealing::new(create_cooling_input(), 1000.0, 0.5, u32::MAX, 1);
+    sa.final_temp = Some(1.0);
+    sa.stop.time_limit = Some(Duration::from_secs(2));
+
+    // Pretend 1000 iterations took one second: about 1000 are left
+    let started = Instant::now() - Duration::from_secs(1);
+    let rate = sa.derived_cooling_rate(1000.0, 1000, started);
+    let remaining = (1.0f64 / 1000.0).ln() / rate.ln();
+    assert!((900.0..1100.0).contains(&remaining), "Expected about 1000 iterations left, got {}", remaining);
+}
+
+#[test]
+fn test_adaptive_run_is_consistent_and_reproducible() {
+    let input = create_cooling_input();
+    let mut sa = SimulatedAnnealing::new(input.clone(), 1.0, 0.5, 5000, 3);
+    sa.target_acceptance = Some(0.8);
+    sa.final_temp = Some(0.5);
+    sa.reheat_after = Some(300);
+
+    let first = sa.solve();
+    let second = sa.solve();
+    assert_eq!(first.penalty, first.schedule.calculate_penalty(&input));
+    assert_eq!(first.schedule.assignments, second.schedule.assignments);
+}
+
+#[test]
+fn test_reheat_ratio_out_of_range_is_an_error() {
+    for value in ["-0.5", "1.0", "2.5", "nan"] {
+        let config: Config = toml::from_str(&format!("{}reheat_ratio = {}\n", BASE_CONFIG, value)).unwrap();
+        assert!(config.validate().is_err(), "reheat_ratio = {} should be rejected", value);
+    }
+    for value in ["0.0", "0.5"] {
+        let config: Config = toml::from_str(&format!("{}reheat_ratio = {}\n", BASE_CONFIG, value)).unwrap();
+        assert_eq!(config.validate(), Ok(()));
+    }
+}
+
+#[test]
+fn test_target_acceptance_out_of_range_is_an_error() {
+    for value in ["0.0", "-0.2", "1.0", "1.5", "nan"] {
+        let config: Config = toml::from_str(&format!("{}target_acceptance = {}\n", BASE_CONFIG, value)).unwrap();
+        assert!(config.validate().is_err(), "target_acceptance = {} should be rejected", value);
+    }
+    let config: Config = toml::from_str(&format!("{}target_acceptance = 0.8\n", BASE_CONFIG)).unwrap();
+    assert_eq!(config.validate(), Ok(()));
+}
@@ -0,0 +1,102 @@
+use rand::{Rng, SeedableRng};
+use rand::rngs::StdRng;
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule,
+    teacher::Teacher,
+};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+
+// --- Helper: A small but crowded world, so random moves hit every constraint ---
+fn create_crowded_input() -> TimetableInput {
+    let rooms = (0..4)
+        .map(|id| Room {
+            id,
+            name: format!("Room {}", id),
+            capacity: if id == 0 { 60 } else { 30 },
+            is_laboratory: id == 3,
+            building_id: id % 2, // Two buildings, so teleportation can happen
+            unavailable: vec![(0, 0), (1, 3)],
+        })
+        .collect();
+
+    let groups = vec![
+        Group { id: 0, name: "G1".to_string(), numbers_of_students: 25, courses: vec![0, 1, 2, 4] },
+        Group { id: 1, name: "G2".to_string(), numbers_of_students: 25, courses: vec![0, 3, 5, 4] },
+    ];
+
+    let courses = (0..6)
+        .map(|id| Course {
+            id,
+            subject_name: format!("Course {}", id),
+            professor_id: id % 2,
+            group_ids: if id == 0 || id == 4 { vec![0, 1] } else { vec![id % 2] },
+            required_hours: if id == 2 { 4 } else { 2 }, // Course 2 needs two sessions
+            required_lab: id == 5,
+        })
+        .collect();
+
+    let teachers = vec![
+        Teacher {
+            id: 0,
+            name: "Prof. A".to_string(),
+            course_id: vec![0, 2, 4],
+            unavailable: vec![(4, 0), (4, 1)],
+            undesired: vec![(0, 5)],
+            ..Default::default()
+        },
+        Teacher {
+            id: 1,
+            name: "Prof. B".to_string(),
+            course_id: vec![1, 3, 5],
+            preferred: vec![(0, 0), (0, 1), (1, 0), (1, 1)],
+            ..Default::default()
+        },
+    ];
+
+    TimetableInput { rooms, teachers, courses, groups, ..Default::default() }
+}
+
+fn random_assignment(rng: &mut impl Rng, input: &TimetableInput) -> (u32, u32, usize) {
+    (rng.gen_range(0..5), rng.gen_range(0..6), rng.gen_range(0..input.rooms.len()))
+}
+
+#[test]
+fn test_evaluator_matches_full_penalty_on_creation() {
+    let input = create_crowded_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 0, 0), (0, 1, 1), (0, 1, 2), (0, 3, 2), (0, 5, 3), (2, 2, 3)]);
+
+    let expected = schedule.calculate_penalty(&input);
+    let evaluator = IncrementalEvaluator::new(&input, schedule);
+    assert_eq!(evaluator.penalty(), expected);
+}
+
+#[test]
+fn test_evaluator_matches_full_penalty_after_random_moves() {
+    let input = create_crowded_input();
+    let mut rng = StdRng::seed_from_u64(13);
+
+    let session_count = Schedule::sessions_for(&input).len();
+    let assignments = (0..session_count).map(|_| random_assignment(&mut rng, &input)).collect();
+    let mut evaluator = IncrementalEvaluator::new(&input, Schedule::new(&input, assignments));
+
+    for _ in 0..5000 {
+        let session_id = rng.gen_range(0..session_count);
+        let penalty = evaluator.apply_move(session_id, random_assignment(&mut rng, &input));
+
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(&input), "Delta and full penalty diverged");
+    }
+}
+
+#[test]
+fn test_evaluator_undo_restores_penalty() {
+    let input = create_crowded_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 0), (0, 2, 1), (0, 3, 1), (1, 0, 2), (1, 1, 0), (1, 2, 3)]);
+    let mut evaluator = IncrementalEvaluator::new(&input, schedule);
+    let before = evaluator.penalty();
+
+    // Put session 1 (course 1) on top of course 0 (group + teacher + room collision), then move it back
+    evaluator.apply_move(1, (0, 0, 0));
+    assert!(evaluator.penalty() > before, "Collision should increase the penalty");
+
+    assert_eq!(evaluator.apply_move(1, (0, 1, 0)), before, "Undoing the move should restore the penalty");
+}
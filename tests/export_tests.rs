@@ -0,0 +1,85 @@
+mod common;
+
+use common::create_raw_input;
+use UCTP::domain::{input_wrapper::TimetableInput, schedule::Schedule};
+use UCTP::io::export::{export_schedule, import_schedule, read_schedule_json, write_schedule_json};
+use UCTP::io::normalize_input::normalize_data;
+
+// --- Helper: The shared raw input, normalized like in main ---
+fn create_normalized_input() -> TimetableInput {
+    normalize_data(create_raw_input())
+}
+
+#[test]
+fn test_export_uses_original_ids() {
+    let input = create_normalized_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (2, 0, 0), (0, 1, 1)]);
+
+    let export = export_schedule(&schedule, &input);
+
+    assert_eq!(export.penalty, schedule.calculate_penalty(&input));
+    assert_eq!(export.sessions.len(), 3);
+
+    let physics = &export.sessions[2];
+    assert_eq!(physics.course_id, 2002);
+    assert_eq!(physics.group_ids, vec![555]);
+    assert_eq!(physics.teacher_id, 10);
+    assert_eq!(physics.room_id, 302);
+    assert_eq!((physics.day, physics.slot), (0, 1));
+    assert_eq!(physics.day_name, "Mon");
+    assert_eq!(physics.slot_label, "10:00-12:00");
+
+    let math_second = &export.sessions[1];
+    assert_eq!((math_second.course_id, math_second.session_index), (1001, 1));
+    assert_eq!(math_second.group_ids, vec![555, 777]);
+}
+
+#[test]
+fn test_import_round_trip() {
+    let input = create_normalized_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (2, 0, 0), (0, 1, 1)]);
+
+    let mut export = export_schedule(&schedule, &input);
+    export.sessions.reverse(); // Order in the file does not matter
+
+    let imported = import_schedule(&export, &input).unwrap();
+    assert_eq!(imported.sessions, schedule.sessions);
+    assert_eq!(imported.assignments, schedule.assignments);
+}
+
+#[test]
+fn test_import_rejects_bad_files() {
+    let input = create_normalized_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (2, 0, 0), (0, 1, 1)]);
+
+    let mut unknown_room = export_schedule(&schedule, &input);
+    unknown_room.sessions[0].room_id = 999;
+    assert!(import_schedule(&unknown_room, &input).is_err(), "Unknown room IDs should be rejected");
+
+    let mut missing_session = export_schedule(&schedule, &input);
+    missing_session.sessions.pop();
+    assert!(import_schedule(&missing_session, &input).is_err(), "Every session must be present");
+
+    let mut duplicated = export_schedule(&schedule, &input);
+    duplicated.sessions[1] = duplicated.sessions[0].clone();
+    assert!(import_schedule(&duplicated, &input).is_err(), "Sessions must not be listed twice");
+
+    let mut outside_week = export_schedule(&schedule, &input);
+    outside_week.sessions[0].day = 7;
+    assert!(import_schedule(&outside_week, &input).is_err(), "Days outside the week should be rejected");
+}
+
+#[test]
+fn test_json_file_round_trip() {
+    let input = create_normalized_input();
+    let schedule = Schedule::new(&input, vec![(1, 2, 0), (3, 4, 0), (4, 5, 1)]);
+
+    let path = std::env::temp_dir().join(format!("uctp_export_{}.json", std::process::id()));
+    let path = path.to_str().unwrap();
+
+    write_schedule_json(path, &schedule, &input).unwrap();
+    let loaded = read_schedule_json(path, &input).unwrap();
+    std::fs::remove_file(path).unwrap();
+
+    assert_eq!(loaded.assignments, schedule.assignments);
+}
@@ -0,0 +1,80 @@
+mod common;
+
+use common::BASE_CONFIG;
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule,
+    teacher::Teacher,
+};
+use UCTP::solver::genetic::GeneticAlgorithm;
+use UCTP::solver::registry::create_solver;
+use UCTP::solver::stop::StopCondition;
+
+// --- Helper: Four groups with two courses each, two rooms ---
+fn create_genetic_input() -> TimetableInput {
+    let courses = (0..8)
+        .map(|id| Course {
+            id: 100 + id, subject_name: format!("Course {}", id), professor_id: id % 3,
+            group_ids: vec![id % 4], required_hours: 4, required_lab: false,
+        })
+        .collect();
+
+    TimetableInput {
+        rooms: (0..2)
+            .map(|id| Room { id, name: format!("Room {}", id), capacity: 40, is_laboratory: false, building_id: 1, unavailable: vec![] })
+            .collect(),
+        groups: (0..4)
+            .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: 30, courses: vec![id, id + 4] })
+            .collect(),
+        courses,
+        teachers: (0..3)
+            .map(|id| Teacher { id, name: format!("Prof. {}", id), course_id: (id..8).step_by(3).collect(), ..Default::default() })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_genetic_never_gets_worse() {
+    let input = create_genetic_input();
+
+    // With 0 generations the result is the best random schedule of the first population
+    let start = GeneticAlgorithm::new(input.clone(), 20, StopCondition::new(0), 3).solve();
+    let evolved = GeneticAlgorithm::new(input.clone(), 20, StopCondition::new(100), 3).solve();
+
+    assert_eq!(evolved.penalty, evolved.schedule.calculate_penalty(&input));
+    assert!(evolved.penalty <= start.penalty, "Elitism keeps the best schedule");
+}
+
+#[test]
+fn test_memetic_polish_reaches_feasibility() {
+    let input = create_genetic_input();
+    let mut memetic = GeneticAlgorithm::new(input.clone(), 10, StopCondition::new(30), 5);
+    memetic.local_search_steps = 300;
+
+    let outcome = memetic.solve();
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&input));
+    assert!(outcome.penalty.is_feasible(), "Only soft penalties should be left, got {}", outcome.penalty);
+}
+
+#[test]
+fn test_genetic_is_reproducible() {
+    let input = create_genetic_input();
+
+    let first = GeneticAlgorithm::new(input.clone(), 10, StopCondition::new(20), 8).solve();
+    let second = GeneticAlgorithm::new(input, 10, StopCondition::new(20), 8).solve();
+    assert_eq!(first.schedule.assignments, second.schedule.assignments);
+}
+
+#[test]
+fn test_genetic_is_registered() {
+    let input = create_genetic_input();
+    let extra = "population_size = 6\nmutation_rate = 0.1\nlocal_search_steps = 10\n";
+    let config: Config = toml::from_str(&format!("{}{}", BASE_CONFIG, extra)).unwrap();
+
+    let solver = create_solver("genetic", input.clone(), StopCondition::new(5), 1, &config).unwrap();
+    assert_eq!(solver.name(), "genetic");
+    let outcome = solver.solve();
+    assert!(outcome.iterations <= 5);
+    assert_eq!(outcome.schedule.assignments.len(), Schedule::sessions_for(&input).len(), "Every session is scheduled");
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&input));
+}
@@ -12,7 +12,7 @@ mod tests {
                     "name": "C309",
                     "capacity": 100,
                     "is_laboratory": false,
-                    "free": [],
+                    "unavailable": [[4, 5]],
                     "building_id": 0
                 }
             ],
@@ -50,6 +50,7 @@ mod tests {
             Ok(data) => {
                 assert_eq!(data.rooms.len(), 1);
                 assert_eq!(data.rooms[0].name, "C309");
+                assert_eq!(data.rooms[0].unavailable, vec![(4, 5)]);
                 assert_eq!(data.teachers.len(), 1);
             },
             Err(e) => {
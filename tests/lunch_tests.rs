@@ -0,0 +1,111 @@
+mod common;
+
+use common::{BASE_CONFIG, assert_evaluator_matches};
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, report::ConstraintKind, room::Room,
+    schedule::Schedule, score::{Level, Score}, teacher::Teacher,
+};
+use UCTP::io::validate_input::{validate_input, ValidationError};
+
+// --- Helper: One group and one teacher with four sessions, lunch window 12:00-16:00 ---
+fn create_lunch_input() -> TimetableInput {
+    let mut input = TimetableInput {
+        rooms: vec![
+            Room { id: 0, name: "Hall".to_string(), capacity: 30, is_laboratory: false, building_id: 0, unavailable: vec![] },
+        ],
+        teachers: vec![Teacher { id: 0, name: "Prof. A".to_string(), course_id: vec![0], ..Default::default() }],
+        courses: vec![Course {
+            id: 0, subject_name: "Algebra".to_string(), professor_id: 0,
+            group_ids: vec![0], required_hours: 8, required_lab: false,
+        }],
+        groups: vec![Group { id: 0, name: "G1".to_string(), numbers_of_students: 20, courses: vec![0] }],
+        ..Default::default()
+    };
+    input.weights.lunch_slots = vec![2, 3];
+    input
+}
+
+// 08:00-16:00 on Monday without a pause
+fn create_non_stop_monday(input: &TimetableInput) -> Schedule {
+    Schedule::new(input, (0..4).map(|slot| (0, slot, 0)).collect())
+}
+
+#[test]
+fn test_full_lunch_window_is_penalized() {
+    let input = create_lunch_input();
+    let schedule = create_non_stop_monday(&input);
+
+    let report = schedule.penalty_report(&input);
+    assert_eq!(report.total, Score::soft(2 * 50), "Both the group and the teacher miss lunch");
+    assert_eq!(report.by_kind()[&ConstraintKind::LunchBreak], (2, 100));
+    assert!(report.violations.iter().all(|v| v.day == Some(0) && v.level == Level::Soft));
+    assert_eq!(report.total, schedule.calculate_penalty(&input));
+}
+
+#[test]
+fn test_one_free_lunch_slot_is_enough() {
+    let mut input = create_lunch_input();
+
+    // 08:00-14:00 on Monday, the last session on Tuesday morning
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 0), (0, 2, 0), (1, 0, 0)]);
+    assert_eq!(schedule.calculate_penalty(&input), Score::ZERO);
+
+    // Without a window nothing is checked
+    let non_stop = create_non_stop_monday(&input);
+    input.weights.lunch_slots.clear();
+    assert_eq!(non_stop.calculate_penalty(&input), Score::ZERO);
+}
+
+#[test]
+fn test_lunch_slots_outside_the_week_are_reported() {
+    let mut input = create_lunch_input();
+    assert_eq!(validate_input(&input), Ok(()));
+
+    input.weights.lunch_slots = vec![2, 9];
+    assert_eq!(validate_input(&input), Err(vec![ValidationError::LunchSlotOutsideWeek { slot: 9 }]));
+}
+
+#[test]
+fn test_lunch_break_can_be_hard() {
+    let mut input = create_lunch_input();
+    input.weights.lunch_break = 1;
+    input.weights.lunch_break_level = Level::Hard;
+
+    let report = create_non_stop_monday(&input).penalty_report(&input);
+    assert_eq!(report.total, Score::hard(2));
+    assert!(report.violations.iter().all(|v| v.level == Level::Hard));
+    assert!(report.to_string().contains("2 hard points"), "{}", report);
+
+    input.weights.disabled = vec![ConstraintKind::LunchBreak];
+    assert_eq!(create_non_stop_monday(&input).calculate_penalty(&input), Score::ZERO);
+}
+
+#[test]
+fn test_evaluator_matches_with_lunch_break() {
+    let mut input = create_lunch_input();
+    input.weights.lunch_slots = vec![1, 2];
+    input.weights.lunch_break_level = Level::Hard;
+
+    assert_evaluator_matches(&input, 3);
+}
+
+#[test]
+fn test_lunch_window_from_config() {
+    let config: Config = toml::from_str(&format!("{}[weights]\nlunch_slots = [2, 3]\nlunch_break_level = \"hard\"\n", BASE_CONFIG)).unwrap();
+    let mut input = TimetableInput::default();
+    config.apply_to(&mut input).unwrap();
+
+    assert_eq!(input.weights.lunch_slots, vec![2, 3]);
+    assert_eq!(input.weights.lunch_break_level, Level::Hard);
+    assert_eq!(input.weights.lunch_break, 50);
+}
+
+#[test]
+fn test_input_lunch_window_survives_other_config_weights() {
+    let mut input = create_lunch_input();
+    let config: Config = toml::from_str(&format!("{}[weights]\nlong_day = 10\n", BASE_CONFIG)).unwrap();
+    config.apply_to(&mut input).unwrap();
+
+    assert_eq!(input.weights.lunch_slots, vec![2, 3]);
+    assert_eq!(create_non_stop_monday(&input).penalty_report(&input).by_kind()[&ConstraintKind::LunchBreak], (2, 100));
+}
@@ -0,0 +1,214 @@
+mod common;
+
+use common::BASE_CONFIG;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule,
+    teacher::Teacher,
+};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::moves::{MoveGenerator, MoveKind, MoveMix, MoveStats};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: Four groups, two teachers, three rooms; every course needs two sessions ---
+fn create_moves_input() -> TimetableInput {
+    let rooms = (0..3)
+        .map(|id| Room {
+            id, name: format!("Room {}", id), capacity: 40, is_laboratory: id == 2,
+            building_id: id % 2, unavailable: vec![(0, 0)],
+        })
+        .collect();
+    let courses = (0..8)
+        .map(|id| Course {
+            id: 100 + id, subject_name: format!("Course {}", id), professor_id: id % 2,
+            group_ids: vec![id % 4], required_hours: 4, required_lab: id == 7,
+        })
+        .collect();
+
+    TimetableInput {
+        rooms,
+        groups: (0..4)
+            .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: 30, courses: vec![id, id + 4] })
+            .collect(),
+        courses,
+        teachers: (0..2)
+            .map(|id| Teacher { id, name: format!("Prof. {}", id), course_id: (id..8).step_by(2).collect(), ..Default::default() })
+            .collect(),
+        ..Default::default()
+    }
+}
+
+fn only(kind: MoveKind) -> MoveMix {
+    let mut mix = MoveMix { reassign: 0.0, swap_times: 0.0, swap_rooms: 0.0, free_room: 0.0, kempe_chain: 0.0 };
+    match kind {
+        MoveKind::Reassign => mix.reassign = 1.0,
+        MoveKind::SwapTimes => mix.swap_times = 1.0,
+        MoveKind::SwapRooms => mix.swap_rooms = 1.0,
+        MoveKind::FreeRoom => mix.free_room = 1.0,
+        MoveKind::KempeChain => mix.kempe_chain = 1.0,
+    }
+    mix
+}
+
+#[test]
+fn test_compound_moves_keep_evaluator_in_sync() {
+    let input = create_moves_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(17);
+    let mut evaluator = IncrementalEvaluator::new(&input, moves.random_schedule(&input, &mut rng));
+
+    for step in 0..3000 {
+        let neighbour = moves.sample_move(&evaluator, &MoveMix::default(), &mut rng);
+        let before = evaluator.penalty();
+        let undo = neighbour.undo(evaluator.schedule());
+
+        let penalty = evaluator.apply_moves(&neighbour.changes);
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(&input), "{:?} diverged", neighbour.kind);
+
+        // Undo every other move
+        if step % 2 == 0 {
+            assert_eq!(evaluator.apply_moves(&undo), before);
+        }
+    }
+
+    // The cell index matches the assignments
+    let schedule = evaluator.schedule();
+    for (session_id, &(day, slot, _)) in schedule.assignments.iter().enumerate() {
+        assert!(evaluator.sessions_at(day, slot).contains(&session_id));
+    }
+}
+
+#[test]
+fn test_swap_rooms_and_free_room_stay_in_the_same_cell() {
+    let input = create_moves_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(3);
+
+    // Sessions 0 and 2 share Monday 10:00 in different rooms; Room 1 is free on Tuesday
+    let mut assignments = vec![(4, 5, 0); 16];
+    assignments[0] = (0, 1, 0);
+    assignments[2] = (0, 1, 1);
+    assignments[4] = (1, 0, 0);
+    let evaluator = IncrementalEvaluator::new(&input, Schedule::new(&input, assignments));
+
+    for _ in 0..200 {
+        for kind in [MoveKind::SwapRooms, MoveKind::FreeRoom] {
+            let neighbour = moves.sample_move(&evaluator, &only(kind), &mut rng);
+            if neighbour.kind == MoveKind::Reassign {
+                continue;
+            }
+            for &(session_id, (day, slot, room_id)) in &neighbour.changes {
+                let (old_day, old_slot, old_room) = evaluator.schedule().assignments[session_id];
+                assert_eq!((day, slot), (old_day, old_slot), "{:?} changed the time", kind);
+                assert_ne!(room_id, old_room, "{:?} kept the room", kind);
+                if kind == MoveKind::FreeRoom {
+                    assert!(evaluator.is_room_free(day, slot, room_id));
+                }
+            }
+        }
+    }
+}
+
+#[test]
+fn test_moves_respect_room_domains_and_blocked_cells() {
+    // A big hall blocked every morning, a small room, one big course and three small ones
+    let input = TimetableInput {
+        rooms: vec![
+            Room {
+                id: 0, name: "Hall".to_string(), capacity: 60, is_laboratory: false, building_id: 0,
+                unavailable: (0..5).flat_map(|day| [(day, 0), (day, 1)]).collect(),
+            },
+            Room { id: 1, name: "Small".to_string(), capacity: 10, is_laboratory: false, building_id: 0, unavailable: vec![] },
+        ],
+        groups: (0..4)
+            .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: if id == 0 { 50 } else { 5 }, courses: vec![id] })
+            .collect(),
+        courses: (0..4)
+            .map(|id| Course {
+                id, subject_name: format!("Course {}", id), professor_id: 0,
+                group_ids: vec![id], required_hours: 8, required_lab: false,
+            })
+            .collect(),
+        teachers: vec![Teacher { id: 0, name: "Prof.".to_string(), course_id: (0..4).collect(), ..Default::default() }],
+        ..Default::default()
+    };
+    let domains = input.room_domains();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(29);
+    let mut evaluator = IncrementalEvaluator::new(&input, moves.random_schedule(&input, &mut rng));
+
+    for kind in MoveKind::ALL {
+        for _ in 0..2000 {
+            let neighbour = moves.sample_move(&evaluator, &only(kind), &mut rng);
+            for &(session_id, (day, slot, room_id)) in &neighbour.changes {
+                let course_id = evaluator.schedule().sessions[session_id].course_id;
+                assert!(domains[course_id].contains(&room_id), "{:?} left the room domain", neighbour.kind);
+                assert!(!input.rooms[room_id].is_unavailable(day, slot), "{:?} used a blocked cell", neighbour.kind);
+            }
+            evaluator.apply_moves(&neighbour.changes);
+        }
+    }
+}
+
+#[test]
+fn test_kempe_chain_moves_connected_sessions_together() {
+    let input = create_moves_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(5);
+
+    // Only two cells in use: Monday 10:00 and Monday 12:00
+    let assignments: Vec<_> = (0..16).map(|s| (0, 1 + (s % 2) as u32, s % 2)).collect();
+    let schedule = Schedule::new(&input, assignments);
+    let evaluator = IncrementalEvaluator::new(&input, schedule.clone());
+
+    let mut seen_chain = false;
+    for _ in 0..500 {
+        let neighbour = moves.sample_move(&evaluator, &only(MoveKind::KempeChain), &mut rng);
+        if neighbour.kind != MoveKind::KempeChain {
+            continue;
+        }
+        for &(session_id, (day, slot, room_id)) in &neighbour.changes {
+            let (old_day, old_slot, old_room) = schedule.assignments[session_id];
+            assert_eq!(room_id, old_room, "Kempe chains keep rooms");
+            assert_ne!((day, slot), (old_day, old_slot));
+        }
+        seen_chain |= neighbour.changes.len() > 1;
+    }
+    assert!(seen_chain, "Shared teachers and groups should pull more sessions into the chain");
+}
+
+#[test]
+fn test_move_stats_are_reported() {
+    let input = create_moves_input();
+    let outcome = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 2000, 1).solve();
+
+    let proposed: u64 = MoveKind::ALL.iter().map(|&kind| outcome.move_stats.proposed(kind)).sum();
+    assert_eq!(proposed, outcome.iterations, "Every iteration proposes one move");
+    for kind in MoveKind::ALL {
+        assert!(outcome.move_stats.accepted(kind) <= outcome.move_stats.proposed(kind));
+    }
+
+    let mut merged = MoveStats::default();
+    merged.merge(&outcome.move_stats);
+    merged.merge(&outcome.move_stats);
+    assert_eq!(merged.proposed(MoveKind::Reassign), 2 * outcome.move_stats.proposed(MoveKind::Reassign));
+    assert!(outcome.move_stats.to_string().contains("Reassign"));
+}
+
+#[test]
+fn test_reassign_only_mix() {
+    let mut sa = SimulatedAnnealing::new(create_moves_input(), 100.0, 0.99, 500, 2);
+    sa.move_mix = MoveMix::reassign_only();
+    let outcome = sa.solve();
+
+    assert_eq!(outcome.move_stats.proposed(MoveKind::Reassign), outcome.iterations);
+}
+
+#[test]
+fn test_move_mix_from_config() {
+    let config: Config = toml::from_str(&format!("{}[move_mix]\nkempe_chain = 0.7\n", BASE_CONFIG)).unwrap();
+
+    assert_eq!(config.move_mix.kempe_chain, 0.7);
+    assert_eq!(config.move_mix.reassign, MoveMix::default().reassign, "Missing weights keep their default");
+}
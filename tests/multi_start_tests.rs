@@ -0,0 +1,73 @@
+use UCTP::domain::{course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher};
+use UCTP::solver::multi_start::MultiStart;
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+use UCTP::solver::stop::StopReason;
+
+// --- Helper: Six groups sharing two rooms and two teachers ---
+fn create_busy_input() -> TimetableInput {
+    let rooms = (0..2)
+        .map(|id| Room { id, name: format!("Room {}", id), capacity: 40, is_laboratory: false, building_id: id, unavailable: vec![] })
+        .collect();
+    let courses = (0..12)
+        .map(|id| Course {
+            id: 100 + id, subject_name: format!("Course {}", id), professor_id: id % 2,
+            group_ids: vec![id % 6], required_hours: 4, required_lab: false,
+        })
+        .collect();
+    let groups = (0..6)
+        .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: 30, courses: vec![id, id + 6] })
+        .collect();
+    let teachers = (0..2)
+        .map(|id| Teacher { id, name: format!("Prof. {}", id), course_id: (id..12).step_by(2).collect(), ..Default::default() })
+        .collect();
+
+    TimetableInput { rooms, groups, courses, teachers, ..Default::default() }
+}
+
+#[test]
+fn test_multi_start_returns_best_chain() {
+    let input = create_busy_input();
+    let sa = SimulatedAnnealing::new(input.clone(), 1000.0, 0.999, 2000, 7);
+
+    let outcome = MultiStart::new(sa.clone(), 4, None).solve();
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&input));
+    assert_eq!(outcome.iterations, 4 * 2000, "Iterations are summed over the chains");
+
+    // Chain i runs on seed + i, so the result is the best of those single runs
+    let best_single = (0..4)
+        .map(|chain| {
+            let mut single = sa.clone();
+            single.seed += chain;
+            single.solve().penalty
+        })
+        .min()
+        .unwrap();
+    assert_eq!(outcome.penalty, best_single);
+}
+
+#[test]
+fn test_multi_start_without_sharing_is_reproducible() {
+    let sa = SimulatedAnnealing::new(create_busy_input(), 1000.0, 0.999, 2000, 3);
+
+    let first = MultiStart::new(sa.clone(), 3, None).solve();
+    let second = MultiStart::new(sa, 3, None).solve();
+    assert_eq!(first.schedule.assignments, second.schedule.assignments);
+}
+
+#[test]
+fn test_multi_start_with_sharing() {
+    let input = create_busy_input();
+    let sa = SimulatedAnnealing::new(input.clone(), 1000.0, 0.999, 5000, 11);
+
+    let outcome = MultiStart::new(sa, 4, Some(250)).solve();
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&input));
+    assert!(matches!(outcome.stop_reason, StopReason::MaxIterations | StopReason::TargetReached));
+}
+
+#[test]
+fn test_zero_chains_runs_one() {
+    let sa = SimulatedAnnealing::new(create_busy_input(), 1000.0, 0.999, 100, 0);
+
+    let outcome = MultiStart::new(sa.clone(), 0, None).solve();
+    assert_eq!(outcome.schedule.assignments, sa.solve().schedule.assignments);
+}
@@ -1,5 +1,5 @@
 use UCTP::domain::{
-    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher,
+    course::Course, group::Group, input_wrapper::TimetableInput, teacher::Teacher,
 };
 use UCTP::io::normalize_input::normalize_data;
 
@@ -14,6 +14,7 @@ fn test_normalization_logic() {
                 id: 10,
                 name: "Prof. X".to_string(),
                 course_id: vec![2002], // Teaches 'Physics' (ID 2002)
+                ..Default::default()
             },
         ],
         courses: vec![
@@ -48,6 +49,7 @@ fn test_normalization_logic() {
                 courses: vec![1001], // Attends only Math (1001)
             },
         ],
+        ..Default::default()
     };
 
     // 2. ACT: Run the normalization
@@ -103,6 +105,7 @@ fn test_normalization_panics_on_bad_id() {
                 courses: vec![99999], // <--- This ID does not exist!
             }
         ],
+        ..Default::default()
     };
 
     normalize_data(bad_input); // Should Panic here
@@ -4,7 +4,7 @@ use UCTP::domain::input_wrapper::TimetableInput;
 #[cfg(test)]
 mod tests {
     use super::*;
-    use UCTP::domain::{course::Course, group::Group, room::Room, teacher::Teacher};
+    use UCTP::domain::{course::Course, group::Group, room::Room, score::Score};
 
     // Helper to create a dummy input with:
     // - 1 Group (100 students)
@@ -15,11 +15,11 @@ mod tests {
             rooms: vec![
                 Room { // Room 0: Big Lecture Hall
                     id: 0, name: "C1".to_string(), capacity: 200, 
-                    is_laboratory: false, free: vec![], building_id: 0 
+                    is_laboratory: false, unavailable: vec![], building_id: 0 
                 },
                 Room { // Room 1: Small Lab
                     id: 1, name: "L1".to_string(), capacity: 50, 
-                    is_laboratory: true, free: vec![], building_id: 0 
+                    is_laboratory: true, unavailable: vec![], building_id: 0 
                 },
             ],
             groups: vec![
@@ -36,6 +36,7 @@ mod tests {
                     group_ids: vec![0], required_hours: 2, required_lab: true
                 },
             ],
+            ..Default::default()
         }
     }
 
@@ -49,19 +50,17 @@ mod tests {
         // Let's testing a VALID assignment first.
         // We put Course 0 in Room 0.
         
-        let schedule = Schedule {
-            assignments: vec![
-                (0, 0, 0), // Mon, 8am, Room 0 (Big Hall) for Course 0
-                (0, 2, 1), // Mon, 12pm, Room 1 (Lab) for Course 1
-            ]
-        };
+        let schedule = Schedule::new(&input, vec![
+            (0, 0, 0), // Mon, 8am, Room 0 (Big Hall) for Course 0
+            (0, 2, 1), // Mon, 12pm, Room 1 (Lab) for Course 1
+        ]);
         
         // NOTE: This should actually FAIL capacity for Course 1 (100 students > 50 cap)
         // Let's see if your code catches it.
         let penalty = schedule.collision_grid(&input);
         
         // Expect: 10,000 penalty (Capacity overflow on Room 1)
-        assert_eq!(penalty, 100000, "Should punish capacity overflow");
+        assert_eq!(penalty, Score::hard(1), "Should punish capacity overflow");
     }
 
     #[test]
@@ -70,15 +69,13 @@ mod tests {
         let mut input = create_mock_input();
         input.rooms[1].capacity = 150; // Now big enough for 100 students
 
-        let schedule = Schedule {
-            assignments: vec![
-                (0, 0, 0), // Course 0 -> Room 0 (Valid)
-                (0, 2, 1), // Course 1 -> Room 1 (Valid Lab)
-            ]
-        };
+        let schedule = Schedule::new(&input, vec![
+            (0, 0, 0), // Course 0 -> Room 0 (Valid)
+            (0, 2, 1), // Course 1 -> Room 1 (Valid Lab)
+        ]);
 
         let penalty = schedule.collision_grid(&input);
-        assert_eq!(penalty, 0, "Perfect schedule should have 0 penalty");
+        assert_eq!(penalty, Score::ZERO, "Perfect schedule should have 0 penalty");
     }
 
     #[test]
@@ -86,12 +83,10 @@ mod tests {
         let mut input = create_mock_input();
         input.rooms[1].capacity = 150; // Fix capacity so we only test collision
 
-        let schedule = Schedule {
-            assignments: vec![
-                (0, 0, 0), // Course 0 -> Monday Slot 0, Room 0
-                (0, 0, 0), // Course 1 -> Monday Slot 0, Room 0 (COLLISION!)
-            ]
-        };
+        let schedule = Schedule::new(&input, vec![
+            (0, 0, 0), // Course 0 -> Monday Slot 0, Room 0
+            (0, 0, 0), // Course 1 -> Monday Slot 0, Room 0 (COLLISION!)
+        ]);
 
         let penalty = schedule.collision_grid(&input);
         
@@ -99,21 +94,19 @@ mod tests {
         // However, Course 1 is a LAB, and Room 0 is NOT a Lab.
         // So Course 1 generates: Collision (10k) AND RoomType Mismatch (10k).
         // Total = 20,000.
-        assert_eq!(penalty, 200000, "Should punish Collision AND Room Type mismatch");
+        assert_eq!(penalty, Score::hard(2), "Should punish Collision AND Room Type mismatch");
     }
 
     #[test]
     fn test_room_type_mismatch() {
         let input = create_mock_input();
         
-        let schedule = Schedule {
-            assignments: vec![
-                (0, 0, 0), // Course 0 (Lecture) -> Room 0 (Hall) -> OK
-                (0, 2, 0), // Course 1 (Lab)     -> Room 0 (Hall) -> ERROR (Not a lab)
-            ]
-        };
+        let schedule = Schedule::new(&input, vec![
+            (0, 0, 0), // Course 0 (Lecture) -> Room 0 (Hall) -> OK
+            (0, 2, 0), // Course 1 (Lab)     -> Room 0 (Hall) -> ERROR (Not a lab)
+        ]);
 
         let penalty = schedule.collision_grid(&input);
-        assert_eq!(penalty, 100000, "Should punish putting a Lab in a Lecture Hall");
+        assert_eq!(penalty, Score::hard(1), "Should punish putting a Lab in a Lecture Hall");
     }
 }
@@ -0,0 +1,248 @@
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, pin::Pin, previous_schedule::PreviousSchedule,
+    room::Room, schedule::Schedule, teacher::Teacher,
+};
+use UCTP::io::normalize_input::normalize_data;
+use UCTP::io::validate_input::{validate_input, ValidationError};
+use UCTP::solver::construct::{greedy_schedule, InitialSolution};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::genetic::GeneticAlgorithm;
+use UCTP::solver::moves::{MoveGenerator, MoveMix};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+use UCTP::solver::stop::StopCondition;
+use UCTP::solver::tabu_search::TabuSearch;
+
+fn pin(course_id: usize, session_index: Option<u32>, day: Option<u32>, slot: Option<u32>, room_id: Option<usize>) -> Pin {
+    Pin { course_id, session_index, day, slot, room_id }
+}
+
+// --- Helper: Three groups, a shared lecture and one lab each (raw Database IDs) ---
+fn create_raw_input() -> TimetableInput {
+    let mut courses = vec![Course {
+        id: 100, subject_name: "Lecture".to_string(), professor_id: 20,
+        group_ids: vec![10, 11, 12], required_hours: 2, required_lab: false,
+    }];
+    courses.extend((0..3).map(|g| Course {
+        id: 101 + g, subject_name: format!("Lab G{}", g), professor_id: 21,
+        group_ids: vec![10 + g], required_hours: 4, required_lab: true,
+    }));
+
+    TimetableInput {
+        rooms: vec![
+            Room { id: 1, name: "Hall".to_string(), capacity: 100, is_laboratory: false, building_id: 1, unavailable: vec![] },
+            Room { id: 2, name: "Lab".to_string(), capacity: 30, is_laboratory: true, building_id: 1, unavailable: vec![] },
+            Room { id: 3, name: "Lab 2".to_string(), capacity: 30, is_laboratory: true, building_id: 1, unavailable: vec![] },
+        ],
+        groups: (0..3)
+            .map(|g| Group { id: 10 + g, name: format!("G{}", g), numbers_of_students: 25, courses: vec![100, 101 + g] })
+            .collect(),
+        courses,
+        teachers: vec![
+            Teacher { id: 20, name: "Lecturer".to_string(), course_id: vec![100], ..Default::default() },
+            Teacher { id: 21, name: "Lab Prof".to_string(), course_id: vec![101, 102, 103], ..Default::default() },
+        ],
+        ..Default::default()
+    }
+}
+
+// The lecture is fixed on Wednesday 14:00 in the Hall, the second session of
+// Lab G0 is on Monday, and every session of Lab G1 is in the first slot of "Lab 2".
+fn create_pinned_input() -> TimetableInput {
+    let mut input = create_raw_input();
+    input.pinned = vec![
+        pin(100, None, Some(2), Some(3), Some(1)),
+        pin(101, Some(1), Some(0), None, None),
+        pin(102, None, None, Some(0), Some(3)),
+    ];
+    normalize_data(input)
+}
+
+fn assert_pins_kept(input: &TimetableInput, schedule: &Schedule) {
+    for (session, &assignment) in schedule.sessions.iter().zip(&schedule.assignments) {
+        if let Some(pin) = input.pin(*session) {
+            assert!(pin.allows(assignment), "{:?} was moved to {:?} against {:?}", session, assignment, pin);
+        }
+    }
+}
+
+#[test]
+fn test_pins_are_read_from_json_and_normalized() {
+    let json = r#"[
+        { "course_id": 100, "day": 2, "slot": 3, "room_id": 1 },
+        { "course_id": 103, "session_index": 1, "room_id": 3 }
+    ]"#;
+    let mut input = create_raw_input();
+    input.pinned = serde_json::from_str(json).unwrap();
+    assert_eq!(input.pinned[1], pin(103, Some(1), None, None, Some(3)));
+
+    let input = normalize_data(input);
+    assert_eq!(input.pinned[0], pin(0, None, Some(2), Some(3), Some(0)));
+    assert_eq!(input.pinned[1], pin(3, Some(1), None, None, Some(2)));
+}
+
+#[test]
+fn test_pin_lookup_per_session() {
+    let input = create_pinned_input();
+    let sessions = Schedule::sessions_for(&input);
+
+    assert_eq!(input.pin(sessions[0]).and_then(Pin::fixed), Some((2, 3, 0)));
+    assert!(input.pin(sessions[1]).is_none(), "Only the second session of Lab G0 is pinned");
+    assert_eq!(input.pin(sessions[2]).unwrap().day, Some(0));
+    assert!(input.pin(sessions[3]).is_some() && input.pin(sessions[4]).is_some());
+    assert!(input.pin(sessions[5]).is_none());
+}
+
+#[test]
+fn test_valid_pins_pass_validation() {
+    let mut input = create_raw_input();
+    input.pinned = vec![pin(100, None, Some(2), Some(3), Some(1)), pin(101, Some(1), Some(2), Some(4), Some(2))];
+    assert_eq!(validate_input(&input), Ok(()));
+}
+
+#[test]
+fn test_clashing_pins_are_reported() {
+    let mut input = create_raw_input();
+    input.pinned = vec![
+        // Same teacher in the same cell
+        pin(101, Some(0), Some(0), Some(0), Some(2)),
+        pin(102, Some(0), Some(0), Some(0), Some(3)),
+        // The lecture shares its groups with Lab G2
+        pin(100, None, Some(1), Some(1), None),
+        pin(103, Some(0), Some(1), Some(1), None),
+        // Both sessions of Lab G1 fixed to one cell
+        pin(102, Some(1), Some(4), Some(5), None),
+        pin(103, Some(1), Some(4), Some(5), Some(3)),
+    ];
+
+    let errors = validate_input(&input).unwrap_err();
+    assert!(errors.contains(&ValidationError::PinClash { course_id: 101, other_course_id: 102, day: 0, slot: 0, shared: "teacher" }));
+    assert!(errors.contains(&ValidationError::PinClash { course_id: 100, other_course_id: 103, day: 1, slot: 1, shared: "group" }));
+    assert!(errors.contains(&ValidationError::PinClash { course_id: 102, other_course_id: 103, day: 4, slot: 5, shared: "teacher" }));
+    assert_eq!(errors.len(), 3, "{:?}", errors);
+}
+
+#[test]
+fn test_room_clash_and_whole_course_pins() {
+    // A guest course with its own teacher and group, so only the room is shared
+    let mut input = create_raw_input();
+    input.courses.push(Course {
+        id: 104, subject_name: "Guest Lab".to_string(), professor_id: 22,
+        group_ids: vec![13], required_hours: 2, required_lab: true,
+    });
+    input.groups.push(Group { id: 13, name: "Guests".to_string(), numbers_of_students: 20, courses: vec![104] });
+    input.teachers.push(Teacher { id: 22, name: "Guest Prof".to_string(), course_id: vec![104], ..Default::default() });
+    input.pinned = vec![
+        pin(101, Some(0), Some(3), Some(2), Some(2)),
+        pin(104, None, Some(3), Some(2), Some(2)),
+        // A course with two sessions cannot have both at one time
+        pin(103, None, Some(4), Some(0), None),
+    ];
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![
+        ValidationError::PinClash { course_id: 101, other_course_id: 104, day: 3, slot: 2, shared: "room" },
+        ValidationError::PinClash { course_id: 103, other_course_id: 103, day: 4, slot: 0, shared: "teacher" },
+    ]);
+}
+
+#[test]
+fn test_bad_pins_are_reported() {
+    let mut input = create_raw_input();
+    input.pinned = vec![
+        pin(999, None, Some(0), None, None),
+        pin(100, None, None, None, Some(42)),
+        pin(101, None, Some(5), None, None),
+        pin(102, Some(2), None, Some(0), None),
+        pin(103, None, None, Some(1), None),
+        pin(103, Some(1), Some(0), None, None),
+    ];
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![
+        ValidationError::DanglingReference { from: "Pin of course", from_id: 999, to: "course", to_id: 999 },
+        ValidationError::DanglingReference { from: "Pin of course", from_id: 100, to: "room", to_id: 42 },
+        ValidationError::PinOutsideWeek { course_id: 101 },
+        ValidationError::PinnedSessionMissing { course_id: 102, session_index: 2 },
+        ValidationError::DuplicatePin { course_id: 103, session_index: 1 },
+    ]);
+}
+
+#[test]
+fn test_pins_with_zero_slot_length_do_not_panic() {
+    let mut input = create_raw_input();
+    input.week.slot_minutes = 0;
+    input.pinned = vec![pin(100, None, Some(2), Some(3), Some(1)), pin(101, Some(1), None, None, None)];
+
+    assert_eq!(validate_input(&input), Err(vec![ValidationError::EmptyWeek]));
+}
+
+#[test]
+fn test_pinned_options_keep_the_pin() {
+    let input = create_pinned_input();
+    let lab_rooms = input.feasible_rooms(2);
+
+    let options = input.pinned_options(&input.pinned[2], &lab_rooms);
+    assert_eq!(options.len(), 5, "First slot of each day, in the pinned room only");
+    assert!(options.iter().all(|&(_, slot, room_id)| slot == 0 && room_id == 2));
+
+    assert_eq!(input.pinned_options(&input.pinned[0], &[]), vec![(2, 3, 0)]);
+}
+
+#[test]
+fn test_starting_schedules_keep_pins() {
+    let input = create_pinned_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(1);
+
+    for _ in 0..50 {
+        assert_pins_kept(&input, &moves.random_schedule(&input, &mut rng));
+    }
+    assert_pins_kept(&input, &greedy_schedule(&input));
+}
+
+#[test]
+fn test_moves_never_break_pins() {
+    let input = create_pinned_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(7);
+    let mut evaluator = IncrementalEvaluator::new(&input, moves.random_schedule(&input, &mut rng));
+
+    for _ in 0..3000 {
+        let neighbour = moves.sample_move(&evaluator, &MoveMix::default(), &mut rng);
+        assert!(neighbour.changes.iter().all(|&(session_id, _)| session_id != 0), "The fixed lecture must never be moved");
+        evaluator.apply_moves(&neighbour.changes);
+        assert_pins_kept(&input, evaluator.schedule());
+    }
+}
+
+#[test]
+fn test_solvers_keep_pins() {
+    let input = create_pinned_input();
+
+    let mut annealing = SimulatedAnnealing::new(input.clone(), 1000.0, 0.999, 5000, 3);
+    annealing.initial = InitialSolution::Greedy;
+    assert_pins_kept(&input, &annealing.solve().schedule);
+
+    let tabu = TabuSearch::new(input.clone(), 10, 20, StopCondition::new(500), 3);
+    assert_pins_kept(&input, &tabu.solve().schedule);
+
+    let mut genetic = GeneticAlgorithm::new(input.clone(), 10, StopCondition::new(50), 3);
+    genetic.mutation_rate = 0.3;
+    genetic.local_search_steps = 20;
+    assert_pins_kept(&input, &genetic.solve().schedule);
+}
+
+#[test]
+fn test_warm_start_yields_to_pins() {
+    let mut input = create_pinned_input();
+    let mut previous = vec![vec![None]; 1];
+    previous[0][0] = Some((0, 0, 1)); // The lecture used to be on Monday morning in "Lab"
+    previous.extend((1..4).map(|_| vec![None, None]));
+    input.previous = Some(PreviousSchedule { assignments: previous, weight: 10 });
+
+    let schedule = InitialSolution::Random.build(&input, &MoveGenerator::new(&input), &mut StdRng::seed_from_u64(0));
+    assert_eq!(schedule.assignments[0], (2, 3, 0));
+    assert_pins_kept(&input, &schedule);
+}
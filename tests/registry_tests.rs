@@ -0,0 +1,87 @@
+mod common;
+
+use common::BASE_CONFIG;
+use UCTP::domain::{config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, score::Score};
+use UCTP::solver::registry::{create_solver, solver_names, DEFAULT_SOLVER};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+use UCTP::solver::stop::{StopCondition, StopReason};
+
+fn create_small_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![Room { id: 1, name: "Room".to_string(), capacity: 50, is_laboratory: false, building_id: 1, unavailable: vec![] }],
+        groups: vec![Group { id: 1, name: "G1".to_string(), numbers_of_students: 30, courses: vec![0] }],
+        courses: vec![Course {
+            id: 101, subject_name: "Math".to_string(), professor_id: 0,
+            group_ids: vec![0], required_hours: 4, required_lab: false,
+        }],
+        teachers: vec![],
+        ..Default::default()
+    }
+}
+
+fn create_config(extra: &str) -> Config {
+    toml::from_str(&format!("{}{}", BASE_CONFIG, extra)).unwrap()
+}
+
+#[test]
+fn test_default_solver_is_registered() {
+    assert!(solver_names().contains(&DEFAULT_SOLVER));
+}
+
+#[test]
+fn test_unknown_solver_lists_the_valid_names() {
+    let config = create_config("");
+    let error = create_solver("hill-climbing-9000", create_small_input(), StopCondition::new(10), 0, &config)
+        .err()
+        .expect("Unknown names should be rejected");
+
+    assert!(error.contains("hill-climbing-9000"));
+    assert!(error.contains(DEFAULT_SOLVER));
+}
+
+#[test]
+fn test_registry_builds_the_same_solver_as_the_constructor() {
+    let config = create_config("");
+    let input = create_small_input();
+
+    let solver = create_solver(DEFAULT_SOLVER, input.clone(), StopCondition::from_config(&config), 5, &config).unwrap();
+    let direct = SimulatedAnnealing::new(input, 100.0, 0.99, 400, 5).solve();
+    let outcome = solver.solve();
+
+    assert_eq!(solver.name(), DEFAULT_SOLVER);
+    assert_eq!(outcome.schedule.assignments, direct.schedule.assignments);
+}
+
+#[test]
+fn test_stop_condition_comes_from_config() {
+    let config = create_config("max_no_improvement = 50\ntarget_penalty = 7\ntime_limit_secs = 1.5");
+    let stop = StopCondition::from_config(&config);
+
+    assert_eq!(stop.max_iterations, 400);
+    assert_eq!(stop.max_no_improvement, Some(50));
+    assert_eq!(stop.target_penalty, Score::soft(7));
+    assert_eq!(stop.time_limit, Some(std::time::Duration::from_millis(1500)));
+}
+
+#[test]
+fn test_invalid_time_limit_in_config_is_an_error() {
+    for value in ["-1.0", "nan", "inf"] {
+        let config = create_config(&format!("time_limit_secs = {}", value));
+        assert!(config.validate().is_err(), "time_limit_secs = {} should be rejected", value);
+        assert_eq!(StopCondition::from_config(&config).time_limit, None, "No panic for {}", value);
+    }
+    assert_eq!(create_config("time_limit_secs = 0.5").validate(), Ok(()));
+}
+
+#[test]
+fn test_algorithm_from_cli() {
+    let mut config = create_config("algorithm = \"simulated-annealing\"\nchains = 2");
+    config.apply_args(["--algorithm".to_string(), "something-else".to_string()]).unwrap();
+    assert_eq!(config.algorithm.as_deref(), Some("something-else"));
+
+    // Chains wrap the annealing in a multi-start run behind the same name
+    let solver = create_solver(DEFAULT_SOLVER, create_small_input(), StopCondition::new(100), 1, &config).unwrap();
+    let outcome = solver.solve();
+    assert_eq!(solver.name(), DEFAULT_SOLVER);
+    assert!(matches!(outcome.stop_reason, StopReason::TargetReached | StopReason::MaxIterations));
+}
@@ -0,0 +1,89 @@
+mod common;
+
+use common::create_lecture_and_lab_input;
+use UCTP::domain::{report::ConstraintKind, schedule::Schedule, score::Score};
+
+#[test]
+fn test_report_total_matches_penalty() {
+    let input = create_lecture_and_lab_input();
+
+    for assignments in [
+        vec![(0, 0, 0), (0, 0, 0)], // Everything collides
+        vec![(0, 0, 0), (0, 1, 1)], // Teleportation + capacity
+        vec![(4, 0, 0), (4, 5, 1)], // Blocked room + gap + long day
+    ] {
+        let schedule = Schedule::new(&input, assignments);
+        let report = schedule.penalty_report(&input);
+
+        assert_eq!(report.total, schedule.calculate_penalty(&input));
+        assert_eq!(report.total, report.violations.iter().map(|v| Score::of(v.level, v.weight)).sum());
+    }
+}
+
+#[test]
+fn test_report_lists_entities_with_original_ids() {
+    let input = create_lecture_and_lab_input();
+
+    // Math in the Hall at 08:00, Physics Lab in the (too small) Lab at 10:00, other building
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]);
+    let report = schedule.penalty_report(&input);
+    let summary = report.by_kind();
+
+    assert_eq!(summary[&ConstraintKind::RoomCapacity], (1, 1));
+    assert_eq!(summary[&ConstraintKind::Teleportation], (2, 2), "Both the group and the teacher teleport");
+    assert_eq!(report.violations.len(), 3);
+
+    let capacity = report.violations.iter().find(|v| v.kind == ConstraintKind::RoomCapacity).unwrap();
+    assert_eq!(capacity.courses, vec![2002]);
+    assert_eq!(capacity.rooms, vec![302]);
+
+    let teleport = report.violations.iter()
+        .find(|v| v.kind == ConstraintKind::Teleportation && !v.teachers.is_empty())
+        .unwrap();
+    assert_eq!(teleport.courses, vec![1001, 2002]);
+    assert_eq!(teleport.rooms, vec![301, 302]);
+    assert_eq!(teleport.teachers, vec![10]);
+    assert_eq!((teleport.day, teleport.slot), (Some(0), Some(1)));
+}
+
+#[test]
+fn test_report_separates_group_and_teacher_collisions() {
+    let input = create_lecture_and_lab_input();
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 0, 0)]);
+    let summary = schedule.penalty_report(&input).by_kind();
+
+    assert_eq!(summary[&ConstraintKind::GroupCollision].0, 1);
+    assert_eq!(summary[&ConstraintKind::TeacherCollision].0, 1);
+    assert_eq!(summary[&ConstraintKind::RoomDoubleBooking].0, 1);
+    assert_eq!(summary[&ConstraintKind::LabMismatch].0, 1);
+}
+
+#[test]
+fn test_report_text_and_json() {
+    let input = create_lecture_and_lab_input();
+    let schedule = Schedule::new(&input, vec![(4, 0, 0), (4, 5, 1)]);
+    let report = schedule.penalty_report(&input);
+
+    let text = report.to_string();
+    assert!(text.contains("RoomUnavailable"));
+    assert!(text.contains("LongDay"));
+
+    let json: serde_json::Value = serde_json::from_str(&report.to_json().unwrap()).unwrap();
+    assert_eq!(json["total"]["hard"], report.total.hard);
+    assert_eq!(json["total"]["soft"], report.total.soft);
+    assert_eq!(json["violations"][0]["level"], "hard");
+    assert_eq!(json["violations"].as_array().unwrap().len(), report.violations.len());
+}
+
+#[test]
+fn test_perfect_schedule_has_empty_report() {
+    let mut input = create_lecture_and_lab_input();
+    input.rooms[1].capacity = 50;
+    input.rooms[1].building_id = 1;
+
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]);
+    let report = schedule.penalty_report(&input);
+
+    assert_eq!(report.total, Score::ZERO);
+    assert!(report.violations.is_empty());
+}
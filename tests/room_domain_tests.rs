@@ -0,0 +1,64 @@
+use UCTP::domain::{course::Course, group::Group, input_wrapper::TimetableInput, room::Room};
+use UCTP::io::validate_input::{validate_room_domains, ValidationError};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: A big lecture, a lab and a seminar, with rooms that suit only some of them ---
+fn create_domain_input() -> TimetableInput {
+    let room = |id: usize, capacity: u32, is_laboratory: bool| Room {
+        id, name: format!("Room {}", id), capacity, is_laboratory, building_id: 1, unavailable: vec![],
+    };
+    let course = |id: usize, group_ids: Vec<usize>, required_lab: bool| Course {
+        id, subject_name: format!("Course {}", id), professor_id: 0, group_ids, required_hours: 2, required_lab,
+    };
+
+    TimetableInput {
+        rooms: vec![room(10, 200, false), room(20, 40, false), room(30, 40, true), room(40, 100, true)],
+        groups: vec![
+            Group { id: 1, name: "G1".to_string(), numbers_of_students: 30, courses: vec![0, 1, 2] },
+            Group { id: 2, name: "G2".to_string(), numbers_of_students: 30, courses: vec![0] },
+        ],
+        courses: vec![course(101, vec![0, 1], false), course(102, vec![0], true), course(103, vec![0], false)],
+        teachers: vec![],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_room_domains_follow_capacity_and_lab() {
+    let input = create_domain_input();
+
+    assert_eq!(input.feasible_rooms(0), vec![0, 3], "60 students: only the big rooms");
+    assert_eq!(input.feasible_rooms(1), vec![2, 3], "Lab course: only laboratories");
+    assert_eq!(input.feasible_rooms(2), vec![0, 1, 2, 3], "Seminars can use any room");
+    assert!(validate_room_domains(&input, &input.room_domains()).is_ok());
+}
+
+#[test]
+fn test_blocked_rooms_leave_the_domain() {
+    let mut input = create_domain_input();
+    for room in &mut input.rooms[2..] {
+        room.unavailable = (0..5).flat_map(|day| (0..6).map(move |slot| (day, slot))).collect();
+    }
+
+    assert_eq!(input.feasible_rooms(1), Vec::<usize>::new());
+    assert_eq!(
+        validate_room_domains(&input, &input.room_domains()),
+        Err(vec![ValidationError::NoFeasibleRoom { course_id: 102 }]),
+        "Empty domains are reported with the original course ID"
+    );
+}
+
+#[test]
+fn test_solver_only_samples_from_domains() {
+    let input = create_domain_input();
+    let domains = input.room_domains();
+
+    for seed in 0..20 {
+        let sa = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 50, seed);
+        let schedule = sa.run();
+
+        for (session, &(_, _, room_id)) in schedule.sessions.iter().zip(&schedule.assignments) {
+            assert!(domains[session.course_id].contains(&room_id), "Room {} is outside the domain", room_id);
+        }
+    }
+}
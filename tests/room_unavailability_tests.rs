@@ -0,0 +1,85 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule, score::Score,
+};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: One room that is blocked on Monday morning and the whole of Tuesday ---
+fn create_blocked_room_input() -> TimetableInput {
+    let mut unavailable = vec![(0, 0), (0, 1)];
+    unavailable.extend((0..6).map(|slot| (1, slot)));
+
+    TimetableInput {
+        rooms: vec![Room {
+            id: 0,
+            name: "Room A".to_string(),
+            capacity: 100,
+            is_laboratory: false,
+            unavailable,
+            building_id: 1,
+        }],
+        groups: vec![Group {
+            id: 0,
+            name: "G1".to_string(),
+            numbers_of_students: 10,
+            courses: vec![0, 1],
+        }],
+        courses: vec![
+            Course {
+                id: 101,
+                subject_name: "Math".to_string(),
+                professor_id: 0,
+                group_ids: vec![0],
+                required_hours: 4,
+                required_lab: false,
+            },
+            Course {
+                id: 102,
+                subject_name: "Physics".to_string(),
+                professor_id: 0,
+                group_ids: vec![0],
+                required_hours: 2,
+                required_lab: false,
+            },
+        ],
+        teachers: vec![],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_blocked_slot_is_hard_violation() {
+    let input = create_blocked_room_input();
+
+    // Math session 0 on Monday 08:00, which is blocked
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (2, 0, 0), (3, 0, 0)]);
+    assert_eq!(schedule.collision_grid(&input), Score::hard(1), "Using a blocked slot should be punished");
+
+    // Same week, but Monday 12:00 is open
+    let schedule = Schedule::new(&input, vec![(0, 2, 0), (2, 0, 0), (3, 0, 0)]);
+    assert_eq!(schedule.collision_grid(&input), Score::ZERO, "Open slots should not be punished");
+}
+
+#[test]
+fn test_open_cells_skip_blocked_slots() {
+    let input = create_blocked_room_input();
+    let open = input.open_cells(0);
+
+    assert_eq!(open.len(), 5 * 6 - 8);
+    assert!(!open.contains(&(0, 1)));
+    assert!(!open.iter().any(|&(day, _)| day == 1), "Tuesday is fully blocked");
+}
+
+#[test]
+fn test_solver_never_uses_blocked_slot() {
+    let input = create_blocked_room_input();
+    let room = input.rooms[0].clone();
+
+    for seed in 0..20 {
+        let sa = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 200, seed);
+        let schedule = sa.run();
+
+        for &(day, slot, _) in &schedule.assignments {
+            assert!(!room.is_unavailable(day, slot), "Solver placed a session in a blocked slot");
+        }
+    }
+}
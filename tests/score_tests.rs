@@ -0,0 +1,86 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, report::ConstraintKind, room::Room,
+    schedule::Schedule, score::{Level, Score}, teacher::Teacher,
+};
+use UCTP::solver::stop::{StopCondition, StopReason};
+use std::time::Instant;
+
+// --- Helper: One group with a six-session course in a single room ---
+fn create_long_day_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![
+            Room { id: 0, name: "Hall".to_string(), capacity: 30, is_laboratory: false, building_id: 0, unavailable: vec![] },
+        ],
+        teachers: vec![Teacher { id: 0, name: "Prof. A".to_string(), course_id: vec![0], ..Default::default() }],
+        courses: vec![Course {
+            id: 0, subject_name: "Marathon".to_string(), professor_id: 0,
+            group_ids: vec![0], required_hours: 12, required_lab: false,
+        }],
+        groups: vec![Group { id: 0, name: "G1".to_string(), numbers_of_students: 20, courses: vec![0] }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_scores_compare_level_by_level() {
+    assert!(Score::hard(1) > Score::soft(u32::MAX), "No soft penalty outweighs a hard violation");
+    assert!(Score::medium(1) > Score::soft(u32::MAX));
+    assert!(Score::hard(1) > Score::medium(u32::MAX));
+    assert!(Score::soft(1) > Score::ZERO);
+
+    let mixed = Score::hard(2) + Score::medium(3) + Score::soft(4);
+    assert_eq!(mixed, Score { hard: 2, medium: 3, soft: 4 });
+    assert_eq!(mixed - Score::soft(4), Score { hard: 2, medium: 3, soft: 0 });
+    assert_eq!(mixed.to_string(), "2hard/3medium/4soft");
+    assert!(!mixed.is_feasible() && Score::soft(5).is_feasible());
+}
+
+#[test]
+fn test_large_penalties_do_not_overflow() {
+    let total: Score = (0..10).map(|_| Score::soft(u32::MAX)).sum();
+    assert_eq!(total.soft, 10 * u64::from(u32::MAX));
+}
+
+#[test]
+fn test_worsening_uses_the_deciding_level() {
+    let current = Score { hard: 1, medium: 0, soft: 500 };
+
+    assert_eq!(Score::hard(2).worsening_from(current), 100000.0);
+    assert_eq!(Score { hard: 1, medium: 1, soft: 0 }.worsening_from(current), 1000.0);
+    assert_eq!(Score { hard: 1, medium: 0, soft: 530 }.worsening_from(current), 30.0);
+    assert_eq!(Score::soft(9999).worsening_from(current), 0.0, "Fixing a hard violation is not a worsening");
+    assert_eq!(current.worsening_from(current), 0.0);
+}
+
+#[test]
+fn test_schedule_keeps_hard_and_soft_apart() {
+    let input = create_long_day_input();
+
+    // All six sessions back to back: a 12-hour day, but nothing hard
+    let long_day = Schedule::new(&input, (0..6).map(|slot| (0, slot, 0)).collect());
+    // Two sessions in the same slot, the rest spread over the week
+    let collision = Schedule::new(&input, vec![(0, 0, 0), (0, 0, 0), (1, 0, 0), (2, 0, 0), (3, 0, 0), (4, 0, 0)]);
+
+    let long_day_score = long_day.calculate_penalty(&input);
+    let collision_score = collision.calculate_penalty(&input);
+    assert_eq!(long_day_score, Score::soft(400), "A long day for both the group and the teacher");
+    assert!(collision_score.hard > 0);
+    assert!(long_day_score < collision_score);
+
+    let report = collision.penalty_report(&input);
+    assert_eq!(report.total, collision_score);
+    assert!(report.violations.iter().all(|v| v.level == v.kind.level()));
+    assert_eq!(ConstraintKind::GroupCollision.level(), Level::Hard);
+    assert_eq!(ConstraintKind::Gap.level(), Level::Soft);
+}
+
+#[test]
+fn test_soft_target_needs_a_feasible_schedule() {
+    let mut stop = StopCondition::new(100);
+    stop.target_penalty = Score::soft(1000);
+    let started = Instant::now();
+
+    assert_eq!(stop.check(1, started, 0, Score::hard(1), 1), None, "A hard violation never reaches a soft target");
+    assert_eq!(stop.check(1, started, 0, Score::medium(1), 1), None);
+    assert_eq!(stop.check(1, started, 0, Score::soft(1000), 1), Some(StopReason::TargetReached));
+}
@@ -0,0 +1,86 @@
+mod common;
+
+use common::BASE_CONFIG;
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher,
+};
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// --- Helper: Enough courses and rooms that random runs end up in different places ---
+fn create_seed_input() -> TimetableInput {
+    let rooms = (0..3)
+        .map(|id| Room {
+            id: 300 + id,
+            name: format!("Room {}", id),
+            capacity: 40,
+            is_laboratory: false,
+            building_id: 1,
+            unavailable: vec![],
+        })
+        .collect();
+
+    let courses = (0..6)
+        .map(|id| Course {
+            id: 100 + id,
+            subject_name: format!("Course {}", id),
+            professor_id: id % 2,
+            group_ids: vec![id % 3],
+            required_hours: 4,
+            required_lab: false,
+        })
+        .collect();
+
+    let groups = (0..3)
+        .map(|id| Group { id, name: format!("G{}", id), numbers_of_students: 30, courses: vec![id, id + 3] })
+        .collect();
+
+    let teachers = (0..2)
+        .map(|id| Teacher { id, name: format!("Prof. {}", id), course_id: vec![id, id + 2, id + 4], ..Default::default() })
+        .collect();
+
+    TimetableInput { rooms, groups, courses, teachers, ..Default::default() }
+}
+
+fn create_config() -> Config {
+    toml::from_str(BASE_CONFIG).unwrap()
+}
+
+#[test]
+fn test_same_seed_gives_same_schedule() {
+    let input = create_seed_input();
+
+    let first = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 500, 42).run();
+    let second = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 500, 42).run();
+
+    assert_eq!(first.assignments, second.assignments, "A seeded run must be reproducible");
+}
+
+#[test]
+fn test_different_seeds_explore_differently() {
+    let input = create_seed_input();
+
+    // Only 10 iterations, so the random first schedule is still visible
+    let schedules: Vec<_> = (0..5)
+        .map(|seed| SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 10, seed).run().assignments)
+        .collect();
+
+    assert!(schedules.iter().any(|s| *s != schedules[0]), "Seeds should drive the RNG");
+}
+
+#[test]
+fn test_seed_from_config_and_cli() {
+    let mut config = create_config();
+    assert_eq!(config.seed, None);
+
+    config.apply_args(["--seed".to_string(), "1234".to_string()]).unwrap();
+    assert_eq!(config.seed, Some(1234));
+}
+
+#[test]
+fn test_bad_cli_arguments_are_errors() {
+    let mut config = create_config();
+
+    assert!(config.apply_args(["--seed".to_string()]).is_err(), "Missing value");
+    assert!(config.apply_args(["--seed".to_string(), "abc".to_string()]).is_err(), "Not a number");
+    assert!(config.apply_args(["--speed".to_string()]).is_err(), "Unknown flag");
+}
@@ -0,0 +1,88 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule, score::Score,
+};
+
+// --- Helper: One group attending a 4-hour course and a 2-hour course ---
+fn create_multi_session_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![Room {
+            id: 0,
+            name: "Room A".to_string(),
+            capacity: 100,
+            is_laboratory: false,
+            unavailable: vec![],
+            building_id: 1,
+        }],
+        groups: vec![Group {
+            id: 0,
+            name: "G1".to_string(),
+            numbers_of_students: 10,
+            courses: vec![0, 1],
+        }],
+        courses: vec![
+            Course {
+                id: 101,
+                subject_name: "Math".to_string(),
+                professor_id: 0,
+                group_ids: vec![0],
+                required_hours: 4, // Two 2-hour sessions
+                required_lab: false,
+            },
+            Course {
+                id: 102,
+                subject_name: "Physics".to_string(),
+                professor_id: 0,
+                group_ids: vec![0],
+                required_hours: 2, // One session
+                required_lab: false,
+            },
+        ],
+        teachers: vec![],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_sessions_follow_required_hours() {
+    let input = create_multi_session_input();
+    let sessions = Schedule::sessions_for(&input);
+
+    assert_eq!(sessions.len(), 3, "4 hours + 2 hours should need 3 sessions");
+    assert_eq!((sessions[0].course_id, sessions[0].index), (0, 0));
+    assert_eq!((sessions[1].course_id, sessions[1].index), (0, 1));
+    assert_eq!((sessions[2].course_id, sessions[2].index), (1, 0));
+}
+
+#[test]
+fn test_odd_hours_round_up() {
+    let mut input = create_multi_session_input();
+    input.courses[0].required_hours = 3;
+    input.courses[1].required_hours = 0;
+
+    assert_eq!(input.courses[0].sessions_needed(&input.week), 2, "3 hours should still need two 2-hour slots");
+    assert_eq!(input.courses[1].sessions_needed(&input.week), 1, "Every course needs at least one session");
+
+    input.courses[0].required_hours = u32::MAX;
+    assert_eq!(input.courses[0].sessions_needed(&input.week), u32::MAX.div_ceil(2), "No overflow for huge hours");
+}
+
+#[test]
+fn test_sessions_of_same_course_collide() {
+    let input = create_multi_session_input();
+
+    // Both Math sessions on Monday 08:00, in the same room
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 0, 0), (1, 0, 0)]);
+
+    // One room double booking + one group collision
+    assert_eq!(schedule.calculate_penalty(&input), Score::hard(2));
+}
+
+#[test]
+fn test_sessions_spread_over_week() {
+    let input = create_multi_session_input();
+
+    // Math on Monday and Wednesday, Physics right after Monday's Math
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (2, 0, 0), (0, 1, 0)]);
+
+    assert_eq!(schedule.calculate_penalty(&input), Score::ZERO, "Every session has its own slot");
+}
@@ -0,0 +1,94 @@
+use std::time::{Duration, Instant};
+use UCTP::domain::{course::Course, group::Group, input_wrapper::TimetableInput, room::Room, score::Score};
+use UCTP::solver::genetic::GeneticAlgorithm;
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+use UCTP::solver::stop::{StopCondition, StopReason};
+
+// --- Helper: A group that needs more sessions than the week has slots, so 0 is never reached ---
+fn create_impossible_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![Room { id: 1, name: "Room".to_string(), capacity: 50, is_laboratory: false, building_id: 1, unavailable: vec![] }],
+        groups: vec![Group { id: 1, name: "G1".to_string(), numbers_of_students: 30, courses: vec![0] }],
+        courses: vec![Course {
+            id: 101, subject_name: "Marathon".to_string(), professor_id: 0,
+            group_ids: vec![0], required_hours: 62, required_lab: false,
+        }],
+        teachers: vec![],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_check_order_and_thresholds() {
+    let started = Instant::now();
+    let mut stop = StopCondition::new(100);
+
+    assert_eq!(stop.check(0, started, 0, Score::ZERO, 1), Some(StopReason::TargetReached));
+    assert_eq!(stop.check(50, started, 0, Score::soft(10), 1), None);
+    assert_eq!(stop.check(100, started, 0, Score::soft(10), 1), Some(StopReason::MaxIterations));
+
+    stop.max_no_improvement = Some(20);
+    assert_eq!(stop.check(50, started, 19, Score::soft(10), 1), None);
+    assert_eq!(stop.check(50, started, 20, Score::soft(10), 1), Some(StopReason::NoImprovement));
+
+    stop.target_penalty = Score::soft(10);
+    assert_eq!(stop.check(50, started, 0, Score::soft(10), 1), Some(StopReason::TargetReached), "The target is inclusive");
+
+    stop.target_penalty = Score::ZERO;
+    stop.time_limit = Some(Duration::ZERO);
+    assert_eq!(stop.check(0, started, 0, Score::soft(10), 1), Some(StopReason::TimeLimit));
+    assert_eq!(stop.check(1, started, 0, Score::soft(10), 1), Some(StopReason::TimeLimit));
+    assert_eq!(stop.check(1, started, 0, Score::soft(10), 1024), None, "The clock is only read every 1024 iterations");
+}
+
+#[test]
+fn test_solver_reports_iteration_limit() {
+    let sa = SimulatedAnnealing::new(create_impossible_input(), 100.0, 0.99, 300, 1);
+    let outcome = sa.solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::MaxIterations);
+    assert_eq!(outcome.iterations, 300);
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&sa.input));
+}
+
+#[test]
+fn test_solver_stops_without_improvement() {
+    let mut sa = SimulatedAnnealing::new(create_impossible_input(), 100.0, 0.99, 1_000_000, 1);
+    sa.stop.max_no_improvement = Some(500);
+    let outcome = sa.solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::NoImprovement);
+    assert!(outcome.iterations < 1_000_000);
+}
+
+#[test]
+fn test_solver_respects_time_limit() {
+    let mut sa = SimulatedAnnealing::new(create_impossible_input(), 100.0, 0.99, u32::MAX, 1);
+    sa.stop.time_limit = Some(Duration::from_millis(50));
+    let outcome = sa.solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::TimeLimit);
+    assert!(outcome.elapsed < Duration::from_secs(5));
+}
+
+#[test]
+fn test_genetic_reads_the_clock_every_generation() {
+    let mut genetic = GeneticAlgorithm::new(create_impossible_input(), 30, StopCondition::new(u32::MAX), 1);
+    genetic.local_search_steps = 200;
+    genetic.stop.time_limit = Some(Duration::from_millis(50));
+    let outcome = genetic.solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::TimeLimit);
+    assert!(outcome.iterations < 1024);
+    assert!(outcome.elapsed < Duration::from_millis(500), "Stopped after {:?}", outcome.elapsed);
+}
+
+#[test]
+fn test_solver_stops_at_target_penalty() {
+    let mut sa = SimulatedAnnealing::new(create_impossible_input(), 100.0, 0.99, u32::MAX, 1);
+    sa.stop.target_penalty = Score { hard: u64::MAX, ..Score::ZERO };
+    let outcome = sa.solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::TargetReached);
+    assert_eq!(outcome.iterations, 0, "Any schedule meets a target of u64::MAX hard points");
+}
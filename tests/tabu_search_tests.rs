@@ -0,0 +1,111 @@
+mod common;
+
+use common::BASE_CONFIG;
+use rand::SeedableRng;
+use rand::rngs::StdRng;
+use std::time::Duration;
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher,
+};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::moves::{MoveGenerator, MoveMix};
+use UCTP::solver::registry::create_solver;
+use UCTP::solver::stop::{StopCondition, StopReason};
+use UCTP::solver::tabu_search::TabuSearch;
+
+// --- Helper: Three groups, a shared lecture and one lab each, but only one lab room ---
+fn create_lab_input() -> TimetableInput {
+    let mut courses = vec![Course {
+        id: 100, subject_name: "Lecture".to_string(), professor_id: 0,
+        group_ids: vec![0, 1, 2], required_hours: 2, required_lab: false,
+    }];
+    courses.extend((0..3).map(|g| Course {
+        id: 101 + g, subject_name: format!("Lab G{}", g), professor_id: 1,
+        group_ids: vec![g], required_hours: 4, required_lab: true,
+    }));
+
+    TimetableInput {
+        rooms: vec![
+            Room { id: 1, name: "Hall".to_string(), capacity: 100, is_laboratory: false, building_id: 1, unavailable: vec![] },
+            Room { id: 2, name: "Lab".to_string(), capacity: 30, is_laboratory: true, building_id: 1, unavailable: vec![] },
+        ],
+        groups: (0..3)
+            .map(|g| Group { id: g, name: format!("G{}", g), numbers_of_students: 25, courses: vec![0, g + 1] })
+            .collect(),
+        courses,
+        teachers: vec![
+            Teacher { id: 0, name: "Lecturer".to_string(), course_id: vec![0], ..Default::default() },
+            Teacher { id: 1, name: "Lab Prof".to_string(), course_id: vec![1, 2, 3], ..Default::default() },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_tabu_search_removes_hard_violations() {
+    let input = create_lab_input();
+    let tabu = TabuSearch::new(input.clone(), 10, 40, StopCondition::new(3000), 9);
+    let outcome = tabu.solve();
+
+    assert_eq!(outcome.penalty, outcome.schedule.calculate_penalty(&input));
+    assert!(outcome.penalty.is_feasible(), "Only soft penalties should be left, got {}", outcome.penalty);
+}
+
+#[test]
+fn test_tabu_search_is_reproducible() {
+    let input = create_lab_input();
+
+    let first = TabuSearch::new(input.clone(), 10, 20, StopCondition::new(200), 4).solve();
+    let second = TabuSearch::new(input, 10, 20, StopCondition::new(200), 4).solve();
+    assert_eq!(first.schedule.assignments, second.schedule.assignments);
+}
+
+#[test]
+fn test_tabu_search_stops_without_improvement() {
+    let mut stop = StopCondition::new(1_000_000);
+    stop.max_no_improvement = Some(100);
+    let outcome = TabuSearch::new(create_lab_input(), 10, 20, stop, 2).solve();
+
+    assert!(matches!(outcome.stop_reason, StopReason::NoImprovement | StopReason::TargetReached));
+}
+
+#[test]
+fn test_candidate_scores_match_the_full_penalty() {
+    // Tabu search scores each candidate by applying it to the evaluator and undoing it again
+    let input = create_lab_input();
+    let moves = MoveGenerator::new(&input);
+    let mut rng = StdRng::seed_from_u64(6);
+    let mut evaluator = IncrementalEvaluator::new(&input, moves.random_schedule(&input, &mut rng));
+
+    for _ in 0..500 {
+        let before = evaluator.penalty();
+        let candidate = moves.sample_move(&evaluator, &MoveMix::default(), &mut rng);
+        let undo = candidate.undo(evaluator.schedule());
+
+        let penalty = evaluator.apply_moves(&candidate.changes);
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(&input));
+        assert_eq!(evaluator.apply_moves(&undo), before, "Undoing a candidate restores the penalty");
+    }
+}
+
+#[test]
+fn test_tabu_search_respects_time_limit_with_large_samples() {
+    // More lab sessions than the week has slots, so the target is never reached
+    let mut input = create_lab_input();
+    input.courses[1].required_hours = 62;
+    let mut stop = StopCondition::new(u32::MAX);
+    stop.time_limit = Some(Duration::from_millis(50));
+    let outcome = TabuSearch::new(input, 10, 2000, stop, 3).solve();
+
+    assert_eq!(outcome.stop_reason, StopReason::TimeLimit);
+    assert!(outcome.elapsed < Duration::from_millis(500), "Stopped after {:?}", outcome.elapsed);
+}
+
+#[test]
+fn test_tabu_search_is_registered() {
+    let config: Config = toml::from_str(&format!("{}tabu_tenure = 5\ntabu_sample_size = 10\n", BASE_CONFIG)).unwrap();
+
+    let solver = create_solver("tabu-search", create_lab_input(), StopCondition::new(50), 1, &config).unwrap();
+    assert_eq!(solver.name(), "tabu-search");
+    assert!(solver.solve().iterations <= 50);
+}
@@ -3,7 +3,7 @@ use UCTP::domain::{
     input_wrapper::TimetableInput,
     room::Room,
     schedule::Schedule,
-    teacher::Teacher,
+    teacher::Teacher, score::Score,
 };
 
 // --- HELPER FUNCTIONS ---
@@ -15,7 +15,7 @@ fn create_dummy_room(id: usize, building_id: usize) -> Room {
         capacity: 50,
         is_laboratory: false,
         building_id,
-        free: vec![], // Assuming this field exists
+        unavailable: vec![],
     }
 }
 
@@ -35,6 +35,7 @@ fn create_dummy_teacher(id: usize, course_ids: Vec<usize>) -> Teacher {
         id,
         name: "Prof. Test".to_string(),
         course_id: course_ids,
+        ..Default::default()
     }
 }
 
@@ -61,6 +62,7 @@ fn test_teacher_double_booking() {
         teachers: vec![teacher],
         groups: vec![], 
         courses,
+        ..Default::default()
     };
 
     // 2. Schedule: Both at Mon 08:00 (Day 0, Slot 0)
@@ -68,11 +70,11 @@ fn test_teacher_double_booking() {
         (0, 0, 0), // Course 0: Mon 08:00 in Room 0
         (0, 0, 1), // Course 1: Mon 08:00 in Room 1
     ];
-    let schedule = Schedule { assignments };
+    let schedule = Schedule::new(&input, assignments);
 
     // 3. Verify: Should trigger heavy penalty
     let penalty = schedule.gap_teleportation_check(&input, &input.teachers, |g| g.course_id.iter().copied());
-    assert!(penalty >= 10000, "Teacher double-booking should have massive penalty");
+    assert!(penalty.hard >= 1, "Teacher double-booking should have massive penalty");
 }
 
 #[test]
@@ -97,6 +99,7 @@ fn test_teacher_teleportation_penalty() {
         teachers: vec![teacher],
         groups: vec![],
         courses,
+        ..Default::default()
     };
 
     // 2. Schedule: Back-to-back
@@ -104,11 +107,11 @@ fn test_teacher_teleportation_penalty() {
         (0, 0, 0), // Course 0: Mon 08:00 in Room 0 (Bldg 1)
         (0, 1, 1), // Course 1: Mon 10:00 in Room 1 (Bldg 2)
     ];
-    let schedule = Schedule { assignments };
+    let schedule = Schedule::new(&input, assignments);
 
     // 3. Verify: Should trigger teleportation penalty
     let penalty = schedule.gap_teleportation_check(&input, &input.teachers, |g| g.course_id.iter().copied());
-    assert!(penalty > 0, "Teacher moving between buildings instantly should be penalized");
+    assert!(penalty.hard > 0, "Teacher moving between buildings instantly should be penalized");
 }
 
 #[test]
@@ -131,6 +134,7 @@ fn test_teacher_safe_schedule() {
         teachers: vec![teacher],
         groups: vec![],
         courses,
+        ..Default::default()
     };
 
     // 2. Schedule: Back-to-back in same building
@@ -138,9 +142,62 @@ fn test_teacher_safe_schedule() {
         (0, 0, 0), // Course 0: Mon 08:00 in Room 0
         (0, 1, 1), // Course 1: Mon 10:00 in Room 1
     ];
-    let schedule = Schedule { assignments };
+    let schedule = Schedule::new(&input, assignments);
 
     // 3. Verify: Should be 0 penalty
     let penalty = schedule.gap_teleportation_check(&input, &input.teachers, |g| g.course_id.iter().copied());
-    assert_eq!(penalty, 0, "Same building movement should be allowed");
+    assert_eq!(penalty, Score::ZERO, "Same building movement should be allowed");
+}
+
+#[test]
+fn test_teacher_unavailable_slot() {
+    // SCENARIO: Teacher does not work on Fridays, but has a class on Friday 08:00
+
+    let rooms = vec![create_dummy_room(0, 1)];
+    let mut teacher = create_dummy_teacher(1, vec![0]);
+    teacher.unavailable = (0..6).map(|slot| (4, slot)).collect();
+
+    let input = TimetableInput {
+        rooms,
+        teachers: vec![teacher],
+        groups: vec![],
+        courses: vec![create_dummy_course(0, 1)],
+        ..Default::default()
+    };
+
+    let schedule = Schedule::new(&input, vec![(4, 0, 0)]);
+    assert_eq!(schedule.teacher_availability_check(&input), Score::hard(1), "Teaching on a day off should be a hard violation");
+
+    let schedule = Schedule::new(&input, vec![(3, 0, 0)]);
+    assert_eq!(schedule.teacher_availability_check(&input), Score::ZERO, "Thursday is fine");
+}
+
+#[test]
+fn test_teacher_preferences_are_soft() {
+    // SCENARIO: Teacher prefers mornings and dislikes Monday 18:00
+
+    let rooms = vec![create_dummy_room(0, 1)];
+    let mut teacher = create_dummy_teacher(1, vec![0, 1]);
+    teacher.preferred = (0..5).flat_map(|day| [(day, 0), (day, 1)]).collect();
+    teacher.undesired = vec![(0, 5)];
+
+    let input = TimetableInput {
+        rooms,
+        teachers: vec![teacher],
+        groups: vec![],
+        courses: vec![create_dummy_course(0, 1), create_dummy_course(1, 1)],
+        ..Default::default()
+    };
+
+    // Both in the morning: no penalty
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (1, 1, 0)]);
+    assert_eq!(schedule.teacher_availability_check(&input), Score::ZERO);
+
+    // One in the afternoon: outside the preferred slots
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (1, 3, 0)]);
+    assert_eq!(schedule.teacher_availability_check(&input), Score::soft(10));
+
+    // One on Monday 18:00: undesired AND outside the preferred slots
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 5, 0)]);
+    assert_eq!(schedule.teacher_availability_check(&input), Score::soft(40));
 }
@@ -0,0 +1,120 @@
+mod common;
+
+use common::{BASE_CONFIG, assert_evaluator_matches};
+use UCTP::domain::{
+    config::Config, course::Course, group::Group, input_wrapper::TimetableInput, report::ConstraintKind, room::Room,
+    schedule::Schedule, score::Score, teacher::{Teacher, TeacherLimits},
+};
+
+fn limits(max_hours_per_day: Option<u32>, max_consecutive_slots: Option<u32>, min_free_days: Option<u32>) -> TeacherLimits {
+    TeacherLimits { max_hours_per_day, max_consecutive_slots, min_free_days }
+}
+
+// --- Helper: One teacher with a six-session course for two groups, two rooms ---
+fn create_load_input() -> TimetableInput {
+    TimetableInput {
+        rooms: (0..2)
+            .map(|id| Room { id, name: format!("R{}", id), capacity: 30, is_laboratory: false, building_id: 0, unavailable: vec![] })
+            .collect(),
+        teachers: vec![Teacher { id: 7, name: "Prof. A".to_string(), course_id: vec![0], ..Default::default() }],
+        courses: vec![Course {
+            id: 0, subject_name: "Analysis".to_string(), professor_id: 7,
+            group_ids: vec![0], required_hours: 12, required_lab: false,
+        }],
+        groups: vec![Group { id: 0, name: "G1".to_string(), numbers_of_students: 20, courses: vec![0] }],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_limits_fall_back_to_the_defaults() {
+    let mut input = create_load_input();
+    input.teacher_limits = limits(Some(6), Some(2), None);
+    input.teachers[0].limits = limits(Some(8), None, Some(1));
+
+    assert_eq!(input.limits_of(0), limits(Some(8), Some(2), Some(1)));
+    assert_eq!(TeacherLimits::default().or(input.teacher_limits), input.teacher_limits);
+}
+
+#[test]
+fn test_daily_hours_and_consecutive_slots() {
+    let mut input = create_load_input();
+    let marathon = Schedule::new(&input, (0..6).map(|slot| (0, slot, 0)).collect());
+    assert_eq!(marathon.teacher_load_check(&input), Score::ZERO, "No limits by default");
+
+    // 6 hours are 3 slots of 2 hours; at most 2 slots back to back
+    input.teacher_limits = limits(Some(6), Some(2), None);
+    let report = marathon.penalty_report(&input);
+    let summary = report.by_kind();
+    assert_eq!(summary[&ConstraintKind::TeacherDailyHours], (1, 3));
+    assert_eq!(summary[&ConstraintKind::TeacherConsecutiveSlots], (1, 4));
+    assert_eq!(marathon.teacher_load_check(&input), Score::hard(7));
+    assert_eq!(report.total, marathon.calculate_penalty(&input));
+
+    let daily = report.violations.iter().find(|v| v.kind == ConstraintKind::TeacherDailyHours).unwrap();
+    assert_eq!((daily.teachers.clone(), daily.day), (vec![7], Some(0)));
+
+    // Three sessions a day, in runs of at most two
+    let spread = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 0), (0, 3, 0), (1, 0, 0), (1, 2, 0), (1, 3, 0)]);
+    assert_eq!(spread.teacher_load_check(&input), Score::ZERO);
+}
+
+#[test]
+fn test_min_free_days() {
+    let mut input = create_load_input();
+    input.teacher_limits = limits(None, None, Some(2));
+
+    // Monday to Thursday: only Friday is free
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (1, 0, 0), (2, 0, 0), (3, 0, 0), (3, 1, 0), (3, 2, 0)]);
+    let report = schedule.penalty_report(&input);
+    assert_eq!(report.by_kind()[&ConstraintKind::TeacherFreeDays], (1, 1));
+    assert_eq!(report.violations.iter().find(|v| v.kind == ConstraintKind::TeacherFreeDays).unwrap().day, None);
+    assert!(report.to_string().contains("TeacherFreeDays"));
+
+    // The teacher's own limit wins over the default
+    input.teachers[0].limits.min_free_days = Some(1);
+    assert_eq!(schedule.teacher_load_check(&input), Score::ZERO);
+
+    input.weights.teacher_free_days = 5;
+    input.teachers[0].limits.min_free_days = Some(4);
+    assert_eq!(schedule.teacher_load_check(&input), Score::hard(15));
+
+    input.weights.teacher_free_days = u32::MAX;
+    assert_eq!(schedule.teacher_load_check(&input).hard, 3 * u64::from(u32::MAX), "No overflow with large weights");
+}
+
+#[test]
+fn test_evaluator_matches_with_teacher_limits() {
+    let mut input = create_load_input();
+    input.teacher_limits = limits(Some(4), Some(1), Some(3));
+
+    assert_evaluator_matches(&input, 21);
+}
+
+#[test]
+fn test_limits_from_config_and_teacher_record() {
+    let config: Config = toml::from_str(BASE_CONFIG).unwrap();
+    assert_eq!(config.teacher_limits, None);
+
+    let config: Config = toml::from_str(&format!("{}[teacher_limits]\nmax_hours_per_day = 8\nmin_free_days = 1\n", BASE_CONFIG)).unwrap();
+    assert_eq!(config.teacher_limits, Some(limits(Some(8), None, Some(1))));
+
+    let mut input = create_load_input();
+    config.apply_to(&mut input).unwrap();
+    assert_eq!(input.teacher_limits, limits(Some(8), None, Some(1)));
+
+    let json = r#"{ "id": 7, "name": "Prof. A", "course_id": [0], "max_consecutive_slots": 2 }"#;
+    let teacher: Teacher = serde_json::from_str(json).unwrap();
+    assert_eq!(teacher.limits, limits(None, Some(2), None));
+    assert!(teacher.unavailable.is_empty());
+}
+
+#[test]
+fn test_config_limits_override_the_input_limit_by_limit() {
+    let mut input = create_load_input();
+    input.teacher_limits = limits(Some(6), Some(2), Some(1));
+
+    let config: Config = toml::from_str(&format!("{}[teacher_limits]\nmax_hours_per_day = 8\n", BASE_CONFIG)).unwrap();
+    config.apply_to(&mut input).unwrap();
+    assert_eq!(input.teacher_limits, limits(Some(8), Some(2), Some(1)), "Limits not in the table keep the input's value");
+}
@@ -0,0 +1,116 @@
+mod common;
+
+use common::assert_evaluator_matches;
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, report::ConstraintKind, room::Room,
+    schedule::Schedule, score::{Level, Score}, teacher::Teacher, travel::TravelTime,
+};
+use UCTP::io::validate_input::{validate_input, ValidationError};
+
+fn travel(from_building: usize, to_building: usize, minutes: u32) -> TravelTime {
+    TravelTime { from_building, to_building, minutes }
+}
+
+// --- Helper: Main building, one across the street and one across town; one group with two courses ---
+fn create_campus_input() -> TimetableInput {
+    let room = |id: usize, building_id: usize| Room {
+        id, name: format!("R{}", id), capacity: 40, is_laboratory: false, building_id, unavailable: vec![],
+    };
+    let mut input = TimetableInput {
+        rooms: vec![room(0, 1), room(1, 2), room(2, 3)],
+        teachers: vec![Teacher { id: 0, name: "Prof. A".to_string(), course_id: vec![0, 1], ..Default::default() }],
+        courses: (0..2)
+            .map(|id| Course {
+                id, subject_name: format!("Course {}", id), professor_id: 0,
+                group_ids: vec![0], required_hours: 2, required_lab: false,
+            })
+            .collect(),
+        groups: vec![Group { id: 0, name: "G1".to_string(), numbers_of_students: 30, courses: vec![0, 1] }],
+        travel_times: vec![travel(1, 2, 10), travel(3, 1, 45)],
+        ..Default::default()
+    };
+    input.week.break_minutes = 15;
+    input
+}
+
+#[test]
+fn test_travel_minutes_lookup() {
+    let input = create_campus_input();
+    assert_eq!(input.travel_minutes(1, 1), Some(0));
+    assert_eq!(input.travel_minutes(2, 1), Some(10), "Travel times hold in both directions");
+    assert_eq!(input.travel_minutes(1, 3), Some(45));
+    assert_eq!(input.travel_minutes(2, 3), None);
+}
+
+#[test]
+fn test_short_trips_are_soft_and_long_trips_hard() {
+    let mut input = create_campus_input();
+
+    // Across the street: 10 minutes for both the group and the teacher
+    let street = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]);
+    assert_eq!(street.calculate_penalty(&input), Score::soft(20));
+
+    // Across town: 45 minutes do not fit in a 15 minute break
+    let town = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 2)]);
+    assert_eq!(town.calculate_penalty(&input), Score::hard(2));
+
+    // No entry for the two buildings
+    let unknown = Schedule::new(&input, vec![(0, 0, 1), (0, 1, 2)]);
+    assert_eq!(unknown.calculate_penalty(&input), Score::hard(2));
+
+    // Not back to back: only the gap counts
+    let apart = Schedule::new(&input, vec![(0, 0, 0), (0, 2, 2)]);
+    assert_eq!(apart.calculate_penalty(&input), Score::soft(2 * 20));
+
+    input.weights.travel_time = 2;
+    assert_eq!(street.calculate_penalty(&input), Score::soft(40));
+    input.weights.travel_time = u32::MAX;
+    assert_eq!(street.calculate_penalty(&input).soft, 20 * u64::from(u32::MAX), "No overflow with large weights");
+    input.week.break_minutes = 5;
+    assert_eq!(street.calculate_penalty(&input), Score::hard(2));
+}
+
+#[test]
+fn test_without_travel_times_every_building_change_teleports() {
+    let mut input = create_campus_input();
+    input.travel_times.clear();
+
+    let street = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]);
+    let report = street.penalty_report(&input);
+    assert_eq!(report.total, Score::hard(2));
+    assert_eq!(report.by_kind()[&ConstraintKind::Teleportation], (2, 2));
+}
+
+#[test]
+fn test_travel_time_is_reported() {
+    let input = create_campus_input();
+    let report = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]).penalty_report(&input);
+
+    assert_eq!(report.by_kind()[&ConstraintKind::TravelTime], (2, 20));
+    let trip = &report.violations[0];
+    assert_eq!((trip.kind, trip.level, trip.weight), (ConstraintKind::TravelTime, Level::Soft, 10));
+    assert_eq!(trip.rooms, vec![0, 1]);
+    assert_eq!((trip.day, trip.slot), (Some(0), Some(1)));
+}
+
+#[test]
+fn test_evaluator_matches_with_travel_times() {
+    let input = create_campus_input();
+    assert_evaluator_matches(&input, 11);
+}
+
+#[test]
+fn test_travel_times_from_json_and_validation() {
+    let mut json = serde_json::to_value(create_campus_input()).unwrap();
+    json["travel_times"] = serde_json::json!([{ "from_building": 1, "to_building": 9, "minutes": 5 }]);
+    json["week"] = serde_json::json!({ "break_minutes": 20 });
+    let input: TimetableInput = serde_json::from_value(json).unwrap();
+
+    assert_eq!(input.travel_times, vec![travel(1, 9, 5)]);
+    assert_eq!(input.week.break_minutes, 20);
+    assert_eq!(input.week.slot_count(), 6, "The rest of the week keeps its default");
+    assert_eq!(validate_input(&input), Err(vec![ValidationError::DanglingReference {
+        from: "Travel time from building", from_id: 1, to: "building", to_id: 9,
+    }]));
+    assert_eq!(validate_input(&create_campus_input()), Ok(()));
+}
@@ -0,0 +1,121 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, teacher::Teacher,
+};
+use UCTP::io::read_input::read_json;
+use UCTP::io::validate_input::{validate_input, ValidationError};
+
+// --- Helper: A small, fully consistent input (raw Database IDs) ---
+fn create_valid_input() -> TimetableInput {
+    TimetableInput {
+        rooms: vec![
+            Room { id: 1, name: "C309".to_string(), capacity: 60, is_laboratory: false, building_id: 0, unavailable: vec![] },
+            Room { id: 2, name: "L1".to_string(), capacity: 30, is_laboratory: true, building_id: 0, unavailable: vec![] },
+        ],
+        teachers: vec![Teacher { id: 10, name: "Prof. X".to_string(), course_id: vec![101, 102], ..Default::default() }],
+        courses: vec![
+            Course {
+                id: 101, subject_name: "Math".to_string(), professor_id: 10,
+                group_ids: vec![555, 777], required_hours: 2, required_lab: false,
+            },
+            Course {
+                id: 102, subject_name: "Physics Lab".to_string(), professor_id: 10,
+                group_ids: vec![555], required_hours: 2, required_lab: true,
+            },
+        ],
+        groups: vec![
+            Group { id: 555, name: "Group A".to_string(), numbers_of_students: 25, courses: vec![101, 102] },
+            Group { id: 777, name: "Group B".to_string(), numbers_of_students: 30, courses: vec![101] },
+        ],
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_valid_input_passes() {
+    assert_eq!(validate_input(&create_valid_input()), Ok(()));
+}
+
+#[test]
+fn test_collects_every_problem() {
+    let mut input = create_valid_input();
+    input.groups[1].courses.push(999); // Dangling course + nothing else
+    input.teachers[0].course_id.push(888); // Dangling course
+    input.courses[0].professor_id = 11; // Dangling teacher, and teacher 10 still lists course 101
+
+    let errors = validate_input(&input).unwrap_err();
+
+    assert!(errors.contains(&ValidationError::DanglingReference { from: "Group", from_id: 777, to: "course", to_id: 999 }));
+    assert!(errors.contains(&ValidationError::DanglingReference { from: "Teacher", from_id: 10, to: "course", to_id: 888 }));
+    assert!(errors.contains(&ValidationError::DanglingReference { from: "Course", from_id: 101, to: "teacher", to_id: 11 }));
+    assert!(errors.contains(&ValidationError::ProfessorMismatch { course_id: 101, teacher_id: 10 }));
+    assert_eq!(errors.len(), 4, "Should report every problem, not just the first one: {:?}", errors);
+}
+
+#[test]
+fn test_duplicate_ids() {
+    let mut input = create_valid_input();
+    input.rooms[1].id = 1;
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![ValidationError::DuplicateId { kind: "room", id: 1 }]);
+}
+
+#[test]
+fn test_course_without_groups() {
+    let mut input = create_valid_input();
+    input.courses[1].group_ids.clear();
+    input.groups[0].courses = vec![101];
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![ValidationError::CourseWithoutGroups { course_id: 102 }]);
+}
+
+#[test]
+fn test_group_mismatch() {
+    let mut input = create_valid_input();
+    input.groups[1].courses.clear(); // Group B no longer lists Math, but Math lists Group B
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![ValidationError::GroupMismatch { course_id: 101, group_id: 777 }]);
+}
+
+#[test]
+fn test_lab_course_without_lab_rooms() {
+    let mut input = create_valid_input();
+    input.rooms[1].is_laboratory = false;
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![ValidationError::NoLabRoom { course_id: 102 }]);
+}
+
+#[test]
+fn test_capacity_exceeds_every_room() {
+    let mut input = create_valid_input();
+    input.groups[0].numbers_of_students = 40; // Math: 70 > 60, Physics Lab: 40 > 30 (lab rooms only)
+
+    let errors = validate_input(&input).unwrap_err();
+    assert_eq!(errors, vec![
+        ValidationError::CapacityExceedsEveryRoom { course_id: 101, needed: 70, largest: 60 },
+        ValidationError::CapacityExceedsEveryRoom { course_id: 102, needed: 40, largest: 30 },
+    ]);
+}
+
+#[test]
+fn test_more_hours_than_the_week_has_slots() {
+    let mut input = create_valid_input();
+    input.courses[0].required_hours = 60; // 30 two-hour slots: the whole week
+    assert_eq!(validate_input(&input), Ok(()));
+
+    input.courses[0].required_hours = 62;
+    input.courses[1].required_hours = u32::MAX;
+    assert_eq!(validate_input(&input), Err(vec![
+        ValidationError::TooManyHours { course_id: 101, required_hours: 62 },
+        ValidationError::TooManyHours { course_id: 102, required_hours: u32::MAX },
+    ]));
+}
+
+#[test]
+fn test_missing_file_is_an_error() {
+    let result = read_json(&"this_file_does_not_exist.json".to_string());
+    assert!(result.is_err(), "A missing file should be reported, not panic");
+}
@@ -0,0 +1,134 @@
+mod common;
+
+use common::{BASE_CONFIG, create_raw_input};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use UCTP::domain::{
+    config::Config, course::Course, input_wrapper::TimetableInput, report::ConstraintKind, schedule::Schedule,
+    score::Score,
+};
+use UCTP::io::export::{export_schedule, previous_schedule};
+use UCTP::io::normalize_input::normalize_data;
+use UCTP::solver::evaluator::IncrementalEvaluator;
+use UCTP::solver::simulated_annealing::SimulatedAnnealing;
+
+// Math twice on Monday morning / Wednesday, Physics in the lab on Monday
+const PREVIOUS: [(u32, u32, usize); 3] = [(0, 0, 0), (2, 0, 0), (0, 1, 1)];
+
+fn create_warm_input(weight: u32) -> TimetableInput {
+    let mut input = normalize_data(create_raw_input());
+    let export = export_schedule(&Schedule::new(&input, PREVIOUS.to_vec()), &input);
+    input.previous = Some(previous_schedule(&export, &input, weight));
+    input
+}
+
+#[test]
+fn test_previous_schedule_maps_an_edited_input() {
+    let input = normalize_data(create_raw_input());
+    let export = export_schedule(&Schedule::new(&input, PREVIOUS.to_vec()), &input);
+
+    // Physics is dropped, Chemistry is new and Math now needs a third session
+    let mut edited = create_raw_input();
+    edited.courses[0].required_hours = 6;
+    edited.courses[1] = Course {
+        id: 3003, subject_name: "Chemistry".to_string(), professor_id: 10,
+        group_ids: vec![777], required_hours: 2, required_lab: false,
+    };
+    edited.groups[0].courses = vec![1001];
+    edited.groups[1].courses = vec![1001, 3003];
+    edited.teachers[0].course_id = vec![1001, 3003];
+    let edited = normalize_data(edited);
+
+    let previous = previous_schedule(&export, &edited, 10);
+
+    assert_eq!(previous.assignments[0], vec![Some((0, 0, 0)), Some((2, 0, 0)), None]);
+    assert_eq!(previous.assignments[1], vec![None], "New courses have no previous assignment");
+    assert_eq!(previous.known_sessions(), 2);
+}
+
+#[test]
+fn test_previous_schedule_skips_unknown_rooms_and_days() {
+    let input = normalize_data(create_raw_input());
+    let mut export = export_schedule(&Schedule::new(&input, PREVIOUS.to_vec()), &input);
+    export.sessions[0].room_id = 999;
+    export.sessions[1].day = 7;
+
+    let previous = previous_schedule(&export, &input, 10);
+
+    assert_eq!(previous.assignments[0], vec![None, None]);
+    assert_eq!(previous.assignments[1], vec![Some((0, 1, 1))]);
+}
+
+#[test]
+fn test_unchanged_schedule_has_no_perturbation() {
+    let input = create_warm_input(10);
+    let schedule = Schedule::new(&input, PREVIOUS.to_vec());
+
+    let report = schedule.penalty_report(&input);
+    assert!(!report.by_kind().contains_key(&ConstraintKind::Perturbation));
+}
+
+#[test]
+fn test_perturbation_counts_time_and_room_changes() {
+    let input = create_warm_input(7);
+    let unchanged = Schedule::new(&input, PREVIOUS.to_vec()).calculate_penalty(&input);
+
+    // Math's second session moves to Thursday and to the lab room
+    let moved = Schedule::new(&input, vec![(0, 0, 0), (3, 0, 1), (0, 1, 1)]);
+
+    let report = moved.penalty_report(&input);
+    assert_eq!(report.by_kind()[&ConstraintKind::Perturbation], (1, 14));
+
+    let without_previous = normalize_data(create_raw_input());
+    assert_eq!(
+        moved.calculate_penalty(&input),
+        moved.calculate_penalty(&without_previous) + Score::soft(14)
+    );
+    assert_eq!(unchanged, Schedule::new(&input, PREVIOUS.to_vec()).calculate_penalty(&without_previous));
+}
+
+#[test]
+fn test_large_perturbation_weight_does_not_overflow() {
+    let input = create_warm_input(u32::MAX);
+    let moved = Schedule::new(&input, vec![(0, 0, 0), (3, 0, 1), (0, 1, 1)]);
+
+    assert_eq!(moved.penalty_report(&input).by_kind()[&ConstraintKind::Perturbation], (1, 2 * u64::from(u32::MAX)));
+}
+
+#[test]
+fn test_evaluator_matches_full_penalty_with_previous() {
+    let input = create_warm_input(25);
+    let mut rng = StdRng::seed_from_u64(42);
+    let mut evaluator = IncrementalEvaluator::new(&input, Schedule::new(&input, PREVIOUS.to_vec()));
+
+    for _ in 0..500 {
+        let session_id = rng.gen_range(0..3);
+        let assignment = (rng.gen_range(0..5), rng.gen_range(0..6), rng.gen_range(0..input.rooms.len()));
+        let penalty = evaluator.apply_moves(&[(session_id, assignment)]);
+        assert_eq!(penalty, evaluator.schedule().calculate_penalty(&input));
+    }
+}
+
+#[test]
+fn test_warm_start_begins_at_the_previous_schedule() {
+    let input = create_warm_input(10);
+
+    // Without iterations the solver returns its starting point
+    let annealing = SimulatedAnnealing::new(input.clone(), 100.0, 0.99, 0, 1);
+    let outcome = annealing.solve();
+
+    assert_eq!(outcome.schedule.assignments, PREVIOUS.to_vec());
+    assert_eq!(outcome.penalty, Schedule::new(&input, PREVIOUS.to_vec()).calculate_penalty(&input));
+}
+
+#[test]
+fn test_warm_start_config_and_cli_flag() {
+    let mut config: Config = toml::from_str(BASE_CONFIG).unwrap();
+    assert_eq!(config.previous_schedule, None);
+    config.apply_args(["--warm-start".to_string(), "schedule.json".to_string()]).unwrap();
+    assert_eq!(config.previous_schedule.as_deref(), Some("schedule.json"));
+
+    let config: Config = toml::from_str(&format!("{}previous_schedule = \"old.json\"\nperturbation_weight = 3", BASE_CONFIG)).unwrap();
+    assert_eq!(config.previous_schedule.as_deref(), Some("old.json"));
+    assert_eq!(config.perturbation_weight, Some(3));
+}
@@ -0,0 +1,97 @@
+use UCTP::domain::{
+    course::Course, group::Group, input_wrapper::TimetableInput, room::Room, schedule::Schedule, score::Score,
+    week_grid::WeekGrid,
+};
+use UCTP::solver::evaluator::IncrementalEvaluator;
+
+// --- Helper: A Mon-Sat week with eight 1.5-hour periods ---
+fn create_saturday_week() -> WeekGrid {
+    WeekGrid {
+        days: ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat"].map(String::from).to_vec(),
+        slots: (0..8).map(|i| format!("P{}", i + 1)).collect(),
+        slot_minutes: 90,
+        break_minutes: 15,
+    }
+}
+
+fn create_input(week: WeekGrid) -> TimetableInput {
+    TimetableInput {
+        rooms: vec![Room {
+            id: 0,
+            name: "Room A".to_string(),
+            capacity: 100,
+            is_laboratory: false,
+            unavailable: vec![],
+            building_id: 1,
+        }],
+        groups: vec![Group {
+            id: 0,
+            name: "G1".to_string(),
+            numbers_of_students: 10,
+            courses: vec![0],
+        }],
+        courses: vec![Course {
+            id: 101,
+            subject_name: "Math".to_string(),
+            professor_id: 0,
+            group_ids: vec![0],
+            required_hours: 3,
+            required_lab: false,
+        }],
+        teachers: vec![],
+        week,
+        ..Default::default()
+    }
+}
+
+#[test]
+fn test_default_week_is_mon_fri_six_slots() {
+    let week = WeekGrid::default();
+    assert_eq!(week.day_count(), 5);
+    assert_eq!(week.slot_count(), 6);
+    assert_eq!(week.slot_minutes, 120);
+}
+
+#[test]
+fn test_session_count_follows_slot_length() {
+    let input = create_input(create_saturday_week());
+
+    // 3 hours = two 90-minute periods (and would also be two 2-hour slots)
+    assert_eq!(input.courses[0].sessions_needed(&input.week), 2);
+
+    let mut long_course = input.courses[0].clone();
+    long_course.required_hours = 4;
+    assert_eq!(long_course.sessions_needed(&input.week), 3, "4 hours need three 90-minute periods");
+    assert_eq!(long_course.sessions_needed(&WeekGrid::default()), 2, "4 hours need two 2-hour slots");
+}
+
+#[test]
+fn test_saturday_and_late_periods_are_scored() {
+    let input = create_input(create_saturday_week());
+
+    // First period and last (8th) period on Saturday: 6 empty periods in between
+    let schedule = Schedule::new(&input, vec![(5, 0, 0), (5, 7, 0)]);
+    let penalty = schedule.calculate_penalty(&input);
+
+    // Gap of 6 periods (5 points) + an 8-period day ((8 - 4)^2 * 50 = 800 points)
+    assert_eq!(penalty, Score::soft(805));
+
+    let evaluator = IncrementalEvaluator::new(&input, schedule);
+    assert_eq!(evaluator.penalty(), penalty, "The evaluator should use the same grid");
+}
+
+#[test]
+fn test_week_section_is_optional_in_json() {
+    let without_week = r#"{ "rooms": [], "teachers": [], "courses": [], "groups": [] }"#;
+    let input: TimetableInput = serde_json::from_str(without_week).unwrap();
+    assert_eq!(input.week, WeekGrid::default());
+
+    let with_week = r#"{
+        "rooms": [], "teachers": [], "courses": [], "groups": [],
+        "week": { "days": ["Mon", "Sat"], "slots": ["08:00-09:30", "09:45-11:15"], "slot_minutes": 90 }
+    }"#;
+    let input: TimetableInput = serde_json::from_str(with_week).unwrap();
+    assert_eq!(input.week.days, vec!["Mon", "Sat"]);
+    assert_eq!(input.week.slot_count(), 2);
+    assert_eq!(input.week.slot_minutes, 90);
+}
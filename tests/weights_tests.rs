@@ -0,0 +1,129 @@
+mod common;
+
+use common::{BASE_CONFIG, assert_evaluator_matches, create_lecture_and_lab_input};
+use UCTP::domain::{
+    config::Config, input_wrapper::TimetableInput, report::ConstraintKind, schedule::Schedule, score::Score,
+    weights::Weights,
+};
+
+// Friday: Math in the blocked Hall at 08:00, Physics Lab in the (too small) Lab
+// in the last slot, so both the group and the teacher have a 4-slot gap and a 6-slot day.
+fn friday_schedule(input: &TimetableInput) -> Schedule {
+    Schedule::new(input, vec![(4, 0, 0), (4, 5, 1)])
+}
+
+#[test]
+fn test_default_weights_keep_the_built_in_scores() {
+    let weights = Weights::default();
+    assert_eq!((1..=5).map(|gap| weights.gap_points(gap)).collect::<Vec<_>>(), vec![20, 15, 10, 5, 5]);
+    assert_eq!(weights.long_day_points(4), 0);
+    assert_eq!(weights.long_day_points(6), 200);
+    assert_eq!(weights.points(ConstraintKind::TeacherCollision), 1);
+    assert_eq!(weights.points(ConstraintKind::TeacherUndesiredSlot), 30);
+
+    let input = create_lecture_and_lab_input();
+    assert_eq!(friday_schedule(&input).calculate_penalty(&input), Score::hard(2) + Score::soft(2 * (5 + 200)));
+}
+
+#[test]
+fn test_custom_weights_and_compact_day() {
+    let mut input = create_lecture_and_lab_input();
+    input.weights.room_capacity = 3;
+    input.weights.gap = vec![7];
+    input.weights.long_day = 1;
+    input.weights.compact_day_slots = 5;
+
+    let schedule = friday_schedule(&input);
+    let report = schedule.penalty_report(&input);
+    let summary = report.by_kind();
+
+    assert_eq!(summary[&ConstraintKind::RoomCapacity], (1, 3));
+    assert_eq!(summary[&ConstraintKind::Gap], (2, 14));
+    assert_eq!(summary[&ConstraintKind::LongDay], (2, 2));
+    assert_eq!(report.total, Score::hard(4) + Score::soft(16));
+    assert_eq!(report.total, schedule.calculate_penalty(&input));
+}
+
+#[test]
+fn test_large_weights_do_not_overflow() {
+    let mut input = create_lecture_and_lab_input();
+    input.weights.long_day = u32::MAX;
+    assert_eq!(input.weights.long_day_points(6), 4 * u64::from(u32::MAX));
+
+    let score = friday_schedule(&input).calculate_penalty(&input);
+    assert_eq!(score.soft, 2 * (5 + 4 * u64::from(u32::MAX)));
+}
+
+#[test]
+fn test_disabled_constraints_are_not_scored() {
+    let mut input = create_lecture_and_lab_input();
+    input.weights.disabled = vec![ConstraintKind::Gap, ConstraintKind::LongDay, ConstraintKind::RoomCapacity];
+    input.weights.teleportation = 0;
+
+    let report = friday_schedule(&input).penalty_report(&input);
+    assert_eq!(report.total, Score::hard(1), "Only the blocked room is left");
+    assert_eq!(report.violations.len(), 1);
+    assert_eq!(report.violations[0].kind, ConstraintKind::RoomUnavailable);
+
+    // Back to back in two buildings: teleportation is switched off by its weight
+    let schedule = Schedule::new(&input, vec![(0, 0, 0), (0, 1, 1)]);
+    assert_eq!(schedule.calculate_penalty(&input), Score::ZERO);
+}
+
+#[test]
+fn test_evaluator_uses_the_weights() {
+    let mut input = create_lecture_and_lab_input();
+    input.weights.group_collision = 4;
+    input.weights.room_double_booking = 2;
+    input.weights.gap = vec![3, 2];
+    input.weights.long_day = 9;
+    input.weights.compact_day_slots = 2;
+    input.weights.disabled = vec![ConstraintKind::TeacherCollision];
+
+    assert_evaluator_matches(&input, 5);
+}
+
+#[test]
+fn test_weights_from_config_and_input_file() {
+    let mut input = create_lecture_and_lab_input();
+    let config: Config = toml::from_str(BASE_CONFIG).unwrap();
+    assert_eq!(config.weights, None);
+    config.apply_to(&mut input).unwrap();
+    assert_eq!(input.weights, Weights::default());
+
+    let config: Config = toml::from_str(&format!("{}[weights]\ngap = [30, 20]\nlong_day = 10\ndisabled = [\"TeacherOutsidePreferred\"]\n", BASE_CONFIG)).unwrap();
+    config.apply_to(&mut input).unwrap();
+    assert_eq!(input.weights.gap, vec![30, 20]);
+    assert_eq!(input.weights.long_day, 10);
+    assert_eq!(input.weights.compact_day_slots, 4, "Missing fields keep their default");
+    assert_eq!(input.weights.points(ConstraintKind::TeacherOutsidePreferred), 0);
+
+    let mut json = serde_json::to_value(create_lecture_and_lab_input()).unwrap();
+    assert_eq!(serde_json::from_value::<TimetableInput>(json.clone()).unwrap().weights, Weights::default());
+    json["weights"] = serde_json::json!({ "teacher_undesired_slot": 50, "disabled": ["Gap"] });
+    let input: TimetableInput = serde_json::from_value(json).unwrap();
+    assert_eq!(input.weights.teacher_undesired_slot, 50);
+    assert_eq!(input.weights.gap_points(1), 0);
+}
+
+#[test]
+fn test_config_weights_override_the_input_key_by_key() {
+    let mut input = create_lecture_and_lab_input();
+    input.weights.disabled = vec![ConstraintKind::Gap];
+    input.weights.teacher_undesired_slot = 50;
+    input.weights.long_day = 3;
+
+    let config: Config = toml::from_str(&format!("{}[weights]\nlong_day = 10\n", BASE_CONFIG)).unwrap();
+    config.apply_to(&mut input).unwrap();
+    assert_eq!(input.weights.long_day, 10);
+    assert_eq!(input.weights.disabled, vec![ConstraintKind::Gap], "Keys not in the table keep the input's value");
+    assert_eq!(input.weights.teacher_undesired_slot, 50);
+    assert_eq!(input.weights.gap, Weights::default().gap);
+}
+
+#[test]
+fn test_config_weights_of_the_wrong_type_are_an_error() {
+    let config: Config = toml::from_str(&format!("{}[weights]\nlong_day = \"ten\"\n", BASE_CONFIG)).unwrap();
+    assert!(config.validate().is_err());
+    assert!(config.apply_to(&mut create_lecture_and_lab_input()).is_err());
+}